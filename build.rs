@@ -0,0 +1,89 @@
+//! Generates the ISO-8859 code page tables from the mapping files in `data/`.
+//! <br>
+//! For each part, two tables are written to `$OUT_DIR/iso8859.rs`:
+//! * `ISO_8859_<part>_DECODE: [char; 256]` - the character for each byte (U+FFFD for undefined bytes).
+//! * `ISO_8859_<part>_ENCODE: [(char, u8); N]` - the bytes that don't map to the code point of the same value,
+//!   sorted by char for binary search.
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const ISO_8859_PARTS: [u32; 5] = [2, 5, 7, 9, 15];
+
+fn parse_hex(field: Option<&str>, path: &str, line: &str) -> u32 {
+    field
+        .and_then(|field| field.strip_prefix("0x"))
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+        .unwrap_or_else(|| panic!("{path}: invalid mapping line {line:?}"))
+}
+
+fn read_mapping(path: &str) -> [char; 256] {
+    let text = fs::read_to_string(path).unwrap_or_else(|err| panic!("{path}: {err}"));
+    let mut decode = [char::REPLACEMENT_CHARACTER; 256];
+    for line in text.lines() {
+        let mapping = line.split('#').next().unwrap_or_default().trim();
+        if mapping.is_empty() {
+            continue;
+        }
+        let mut fields = mapping.split_whitespace();
+        let byte = parse_hex(fields.next(), path, line);
+        let code_point = parse_hex(fields.next(), path, line);
+        let character = char::from_u32(code_point)
+            .unwrap_or_else(|| panic!("{path}: invalid code point in {line:?}"));
+        let slot = decode
+            .get_mut(byte as usize)
+            .unwrap_or_else(|| panic!("{path}: byte out of range in {line:?}"));
+        *slot = character;
+    }
+    decode
+}
+
+fn write_tables(out: &mut String, part: u32, decode: &[char; 256]) {
+    writeln!(out, "static ISO_8859_{part}_DECODE: [char; 256] = [").unwrap();
+    for chunk in decode.chunks(8) {
+        out.push_str("   ");
+        for character in chunk {
+            write!(out, " '\\u{{{:04X}}}',", *character as u32).unwrap();
+        }
+        out.push('\n');
+    }
+    out.push_str("];\n\n");
+
+    let mut encode: Vec<(char, u8)> = decode
+        .iter()
+        .enumerate()
+        .filter(|(byte, character)| {
+            **character != char::REPLACEMENT_CHARACTER && **character as u32 != *byte as u32
+        })
+        .map(|(byte, character)| (*character, byte as u8))
+        .collect();
+    encode.sort_unstable();
+    writeln!(
+        out,
+        "static ISO_8859_{part}_ENCODE: [(char, u8); {}] = [",
+        encode.len()
+    )
+    .unwrap();
+    for (character, byte) in encode {
+        writeln!(
+            out,
+            "    ('\\u{{{:04X}}}', 0x{byte:02X}),",
+            character as u32
+        )
+        .unwrap();
+    }
+    out.push_str("];\n\n");
+}
+
+fn main() {
+    let mut out = String::new();
+    for part in ISO_8859_PARTS {
+        let path = format!("data/8859-{part}.TXT");
+        println!("cargo:rerun-if-changed={path}");
+        write_tables(&mut out, part, &read_mapping(&path));
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("iso8859.rs"), out).expect("writing iso8859.rs");
+}
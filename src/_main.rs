@@ -1,15 +1,27 @@
+mod ascii_7bit;
+mod ascii_case;
+mod ascii_char;
+mod ascii_codepage;
+mod ascii_ebcdic;
 mod ascii_consts;
+mod ascii_error;
 mod ascii_translators;
 
 use ascii_translators::*;
 use std::io::Read;
 
+mod ascii_str;
 mod ascii_string;
+mod ascii_lossy;
 mod ascii_traits;
+mod ascii_transliterate;
 use ascii_consts::*;
 use ascii_string::*;
 use ascii_traits::*;
 mod ascii_group;
+mod ascii_pattern;
+mod ascii_printable;
+mod ascii_split;
 use ascii_group::*;
 mod ascii_stream;
 use ascii_stream::*;
@@ -64,6 +76,7 @@ pub fn main() {
     }
 
     perf_test();
+    perf_try_from_test();
     perf_stream_test();
 
     #[cfg(feature = "async")]
@@ -171,6 +184,44 @@ fn perf_test() {
     }
 }
 
+/// The char by char `TryFrom<&str>` conversion, before the 16 byte block fast path.
+fn try_from_by_char(value: &str) -> AsciiString {
+    let mut result = AsciiString::with_capacity(value.len());
+    for character in value.chars() {
+        result.push(character.ascii_ord_unchecked());
+    }
+    result
+}
+
+/// Returns the fastest of 10 runs, in microseconds.
+fn best_of_10<F: FnMut() -> usize>(mut f: F) -> u128 {
+    (0..10)
+        .map(|_| {
+            let start = std::time::Instant::now();
+            std::hint::black_box(f());
+            start.elapsed().as_micros()
+        })
+        .min()
+        .unwrap()
+}
+
+fn perf_try_from_test() {
+    let samples = [
+        ("7-bit", "The quick brown fox jumps over the lazy dog. ".repeat(250_000)),
+        ("mixed", "Café résumé naïve, déjà vu. ".repeat(400_000)),
+        ("extended", "éàüñ".repeat(2_500_000)),
+    ];
+    for (name, string) in samples {
+        let by_char = best_of_10(|| try_from_by_char(&string).len());
+        let fast = best_of_10(|| AsciiString::try_from(string.as_str()).unwrap().len());
+        println!("TryFrom<&str> {name} ({} bytes): by char {by_char} us, fast path {fast} us", string.len());
+
+        let ascii_string = AsciiString::try_from(string.as_str()).unwrap();
+        let to_string = best_of_10(|| String::from(&ascii_string).len());
+        println!("String::from {name}: {to_string} us");
+    }
+}
+
 fn perf_stream_test() {
     {
         let file_name = "C:/Temp/EnglishWords/words_ansi.txt"; // 5 MB file
@@ -228,6 +279,45 @@ fn perf_stream_test() {
         let count = writer.into_inner().expect("Wont fail").len();
         println!("AsciiStreamReader: read_line()/write_line() {l_count} lines, {count} bytes, in {end} ms",);
     }
+    {
+        // the old read_until: a fresh Vec per line, which then replaced the caller's AsciiString.
+        let file_name = "C:/Temp/EnglishWords/words_ansi.txt"; // 5 MB file
+        let file = std::fs::File::open(file_name).unwrap();
+        let mut reader = std::io::BufReader::new(file);
+        let mut l_count = 0;
+        let mut line = AsciiString::new();
+        let start = std::time::Instant::now();
+
+        loop {
+            let mut vec = Vec::new();
+            if std::io::BufRead::read_until(&mut reader, LF, &mut vec).unwrap() == 0 {
+                break;
+            }
+            line = AsciiString::from(vec);
+            l_count += 1;
+        }
+
+        let end = start.elapsed().as_millis();
+        println!(
+            "allocating read_until: {l_count} lines read in {end} ms (last line {} bytes)",
+            line.len()
+        );
+    }
+    {
+        let file_name = "C:/Temp/EnglishWords/words_ansi.txt"; // 5 MB file
+        let file = std::fs::File::open(file_name).unwrap();
+        let mut reader = AsciiStreamReader::new(file);
+        let mut l_count = 0;
+        let mut line = AsciiString::new();
+        let start = std::time::Instant::now();
+
+        while reader.read_until(LF, &mut line).unwrap() > 0 {
+            l_count += 1;
+        }
+
+        let end = start.elapsed().as_millis();
+        println!("AsciiStreamReader: in place read_until() {l_count} lines read in {end} ms",);
+    }
 }
 
 #[cfg(feature = "async")]
@@ -303,6 +393,50 @@ async fn perf_async_stream_test() {
         let end = start.elapsed().as_millis();
         println!("AsciiStreamReader: read_line() println in {end} ms",);
     }
+    {
+        // the old read_until: a fresh Vec per line, which then replaced the caller's AsciiString.
+        use futures::io::{AsyncBufReadExt, BufReader};
+        use tokio_util::compat::*;
+
+        let file_name = "C:/Temp/EnglishWords/words_ansi.txt"; // 5 MB file
+        let file = tokio::fs::File::open(file_name).await.unwrap();
+        let mut reader = BufReader::new(file.compat());
+        let mut l_count = 0;
+        let mut line = AsciiString::new();
+        let start = std::time::Instant::now();
+
+        loop {
+            let mut vec = Vec::new();
+            if reader.read_until(LF, &mut vec).await.unwrap() == 0 {
+                break;
+            }
+            line = AsciiString::from(vec);
+            l_count += 1;
+        }
+
+        let end = start.elapsed().as_millis();
+        println!(
+            "allocating async read_until: {l_count} lines read in {end} ms (last line {} bytes)",
+            line.len()
+        );
+    }
+    {
+        use tokio_util::compat::*;
+
+        let file_name = "C:/Temp/EnglishWords/words_ansi.txt"; // 5 MB file
+        let file = tokio::fs::File::open(file_name).await.unwrap();
+        let mut reader = AsciiStreamReaderAsync::new(file.compat());
+        let mut l_count = 0;
+        let mut line = AsciiString::new();
+        let start = std::time::Instant::now();
+
+        while reader.read_until(LF, &mut line).await.unwrap() > 0 {
+            l_count += 1;
+        }
+
+        let end = start.elapsed().as_millis();
+        println!("AsciiStreamReaderAsync: in place read_until() {l_count} lines read in {end} ms",);
+    }
     {
         use cj_ascii::prelude::*;
         use tokio_util::compat::*;
@@ -0,0 +1,347 @@
+use crate::ascii_error::AsciiError;
+use crate::ascii_str::AsciiStr;
+use crate::ascii_string::AsciiString;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Deref;
+
+/// A 7-bit clean `AsciiString`: every byte is guaranteed to be in 0x00..=0x7F.
+/// <br>
+/// Use it where a protocol requires pure 7-bit ASCII (SMTP headers, FIX, etc.),
+/// so the type system tracks which data has been validated.
+/// * constructors reject Extended ASCII (0x80..=0xFF) and non ASCII characters.
+/// * derefs to `AsciiStr` (read only), so all the searching/splitting methods are available.
+/// * 7-bit ASCII is valid UTF-8, so `as_str()` borrows the bytes as a `&str` without copying.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// let header = Ascii7String::try_from("Subject: Hello").unwrap();
+/// assert_eq!(header.as_str(), "Subject: Hello");
+/// assert!(header.starts_with("Subject"));
+///
+/// let error = Ascii7String::try_from("Café").unwrap_err();
+/// assert_eq!(error.kind(), AsciiErrorKind::Extended);
+/// assert_eq!(error.char_index(), 3);
+///
+/// let astring = AsciiString::try_from("Cafe").unwrap();
+/// assert!(astring.is_ascii7());
+/// let ascii7 = astring.into_ascii7().unwrap();
+/// let astring: AsciiString = ascii7.into();
+/// assert_eq!(astring.to_string(), "Cafe");
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Ascii7String {
+    inner: AsciiString,
+}
+
+/// Returns an error for the first byte that is not 7-bit ASCII.
+#[inline]
+fn check_ascii7(bytes: &[u8]) -> Result<(), AsciiError> {
+    match bytes.iter().position(|byte| !byte.is_ascii()) {
+        None => Ok(()),
+        Some(inx) => Err(AsciiError::extended(bytes[inx] as char, inx, inx)),
+    }
+}
+
+impl Ascii7String {
+    /// Creates a new empty `Ascii7String`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Creates a new empty `Ascii7String` with the specified capacity.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: AsciiString::with_capacity(capacity),
+        }
+    }
+    /// Returns the contents as a `&str`, without copying.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: every byte is 7-bit ASCII, which is valid UTF-8.
+        unsafe { std::str::from_utf8_unchecked(self.inner.as_bytes()) }
+    }
+    /// Returns the contents as an `AsciiStr`.
+    #[inline]
+    pub fn as_ascii_str(&self) -> &AsciiStr {
+        self.inner.as_ascii_str()
+    }
+    /// Converts into an `AsciiString`, without copying.
+    #[inline]
+    pub fn into_ascii_string(self) -> AsciiString {
+        self.inner
+    }
+    /// Pushes a character onto the end of the string.
+    /// # Errors
+    /// * if the character is not 7-bit ASCII.
+    pub fn try_push(&mut self, character: char) -> Result<(), AsciiError> {
+        match character {
+            '\0'..='\x7F' => {
+                self.inner.push(character as u8);
+                Ok(())
+            }
+            '\u{80}'..='\u{FF}' => Err(AsciiError::extended(character, self.len(), self.len())),
+            _ => Err(AsciiError::non_ascii(character, self.len(), self.len())),
+        }
+    }
+    /// Pushes a string onto the end of the string.
+    /// # Errors
+    /// * if the string contains any characters that are not 7-bit ASCII (the string is left unchanged).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut ascii7 = Ascii7String::new();
+    /// ascii7.try_push_str("8=FIX.4.4").unwrap();
+    /// assert!(ascii7.try_push_str("|9=é").is_err());
+    /// assert_eq!(ascii7.as_str(), "8=FIX.4.4");
+    /// ```
+    pub fn try_push_str(&mut self, string: &str) -> Result<(), AsciiError> {
+        if !string.is_ascii() {
+            // report the first offending character, the same as AsciiString::try_from.
+            let (char_index, (byte_index, character)) = string
+                .char_indices()
+                .enumerate()
+                .find(|(_, (_, character))| !character.is_ascii())
+                .expect("string is not ascii");
+            return Err(if (character as u32) < 0x100 {
+                AsciiError::extended(character, char_index, byte_index)
+            } else {
+                AsciiError::non_ascii(character, char_index, byte_index)
+            });
+        }
+        self.inner.push_bytes(string.as_bytes());
+        Ok(())
+    }
+    /// Removes the last character and returns it, or `None` if the string is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        self.inner.pop().map(char::from)
+    }
+    /// Shortens the string to the specified length (does nothing if it is already shorter).
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len);
+    }
+    /// Removes all contents.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+}
+
+impl AsciiStr {
+    /// Returns true if all bytes are 7-bit ASCII (0x00..=0x7F).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// assert!(AsciiString::try_from("abc").unwrap().is_ascii7());
+    /// assert!(!AsciiString::try_from("abç").unwrap().is_ascii7());
+    /// ```
+    #[inline]
+    pub fn is_ascii7(&self) -> bool {
+        self.as_bytes().is_ascii()
+    }
+}
+
+impl AsciiString {
+    /// Converts into an `Ascii7String`, without copying.
+    /// # Errors
+    /// * if any byte is Extended ASCII (0x80..=0xFF).
+    #[inline]
+    pub fn into_ascii7(self) -> Result<Ascii7String, AsciiError> {
+        check_ascii7(self.as_bytes())?;
+        Ok(Ascii7String { inner: self })
+    }
+}
+
+impl Deref for Ascii7String {
+    type Target = AsciiStr;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ascii_str()
+    }
+}
+
+impl AsRef<AsciiStr> for Ascii7String {
+    #[inline]
+    fn as_ref(&self) -> &AsciiStr {
+        self.as_ascii_str()
+    }
+}
+
+impl AsRef<str> for Ascii7String {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for Ascii7String {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.inner.as_bytes()
+    }
+}
+
+impl PartialEq<str> for Ascii7String {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Ascii7String {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl TryFrom<&str> for Ascii7String {
+    type Error = AsciiError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut result = Self::with_capacity(value.len());
+        result.try_push_str(value)?;
+        Ok(result)
+    }
+}
+
+impl TryFrom<String> for Ascii7String {
+    type Error = AsciiError;
+
+    #[inline]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<AsciiString> for Ascii7String {
+    type Error = AsciiError;
+
+    #[inline]
+    fn try_from(value: AsciiString) -> Result<Self, Self::Error> {
+        value.into_ascii7()
+    }
+}
+
+impl TryFrom<&AsciiStr> for Ascii7String {
+    type Error = AsciiError;
+
+    #[inline]
+    fn try_from(value: &AsciiStr) -> Result<Self, Self::Error> {
+        check_ascii7(value.as_bytes())?;
+        Ok(Self {
+            inner: value.to_owned(),
+        })
+    }
+}
+
+impl From<Ascii7String> for AsciiString {
+    #[inline]
+    fn from(value: Ascii7String) -> Self {
+        value.inner
+    }
+}
+
+impl From<Ascii7String> for String {
+    #[inline]
+    fn from(value: Ascii7String) -> Self {
+        // SAFETY: every byte is 7-bit ASCII, which is valid UTF-8.
+        unsafe { String::from_utf8_unchecked(value.inner.into_bytes()) }
+    }
+}
+
+impl Display for Ascii7String {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl Debug for Ascii7String {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+impl Serialize for Ascii7String {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Ascii7String {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        Self::try_from(string).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ascii_error::AsciiErrorKind;
+
+    #[test]
+    fn test_validation() {
+        let error = Ascii7String::try_from("ab€").unwrap_err();
+        assert_eq!(error.kind(), AsciiErrorKind::NonAscii);
+        assert_eq!((error.char_index(), error.byte_index()), (2, 2));
+        let error = Ascii7String::try_from("€é").unwrap_err();
+        assert_eq!(error.kind(), AsciiErrorKind::NonAscii);
+        let error = Ascii7String::try_from("aé€").unwrap_err();
+        assert_eq!(error.kind(), AsciiErrorKind::Extended);
+        assert_eq!(error.character(), 'é');
+
+        let mut astring = AsciiString::try_from("abc").unwrap();
+        astring.push(0xFF);
+        let error = astring.clone().into_ascii7().unwrap_err();
+        assert_eq!(error.kind(), AsciiErrorKind::Extended);
+        assert_eq!((error.character(), error.char_index()), ('ÿ', 3));
+        assert!(Ascii7String::try_from(&astring[..2]).is_ok());
+        assert!(Ascii7String::try_from(astring.as_ascii_str()).is_err());
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut ascii7 = Ascii7String::new();
+        ascii7.try_push('a').unwrap();
+        assert_eq!(
+            ascii7.try_push('é').unwrap_err().kind(),
+            AsciiErrorKind::Extended
+        );
+        assert_eq!(
+            ascii7.try_push('€').unwrap_err().kind(),
+            AsciiErrorKind::NonAscii
+        );
+        ascii7.try_push_str("bcd").unwrap();
+        assert_eq!(ascii7, "abcd");
+        assert_eq!(ascii7.pop(), Some('d'));
+        ascii7.truncate(1);
+        assert_eq!(ascii7.to_string(), "a");
+        assert_eq!(format!("{ascii7:?}"), "\"a\"");
+        ascii7.clear();
+        assert!(ascii7.is_empty());
+        assert_eq!(String::from(ascii7), "");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let ascii7 = Ascii7String::try_from("abc").unwrap();
+        let json = serde_json::to_string(&ascii7).unwrap();
+        assert_eq!(json, "\"abc\"");
+        assert_eq!(serde_json::from_str::<Ascii7String>(&json).unwrap(), ascii7);
+        assert!(serde_json::from_str::<Ascii7String>("\"é\"").is_err());
+    }
+}
@@ -0,0 +1,232 @@
+use crate::ascii_str::AsciiStr;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// Chooses which characters case conversion and case-insensitive comparison apply to.
+/// <br>
+/// The Latin-1 mapping pairs 0xC0..=0xDE with 0xE0..=0xFE, with these exceptions (left unchanged):
+/// * `×` (0xD7) and `÷` (0xF7) are not letters.
+/// * `ß` (0xDF) has no single-character uppercase (it uppercases to "SS").
+/// * `ÿ` (0xFF) uppercases to `Ÿ` (U+0178), which is not Latin-1.
+/// * `µ` (0xB5) uppercases to Greek `Μ` (U+039C), which is not Latin-1.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// assert_eq!(CaseMode::Ascii7.to_uppercase(0xE9), 0xE9);
+/// assert_eq!(CaseMode::Latin1.to_uppercase(0xE9), 0xC9); // é -> É
+/// assert_eq!(CaseMode::Latin1.to_uppercase(0xDF), 0xDF); // ß is unchanged
+/// assert_eq!(CaseMode::Latin1.to_uppercase(0xFF), 0xFF); // ÿ is unchanged
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CaseMode {
+    /// Only `A`-`Z` and `a`-`z` are mapped (the same as `u8::to_ascii_uppercase`).
+    #[default]
+    Ascii7,
+    /// `A`-`Z`, `a`-`z` and the Latin-1 (ISO-8859-1) letters in 0xC0..=0xFE are mapped.
+    Latin1,
+}
+
+impl CaseMode {
+    /// Returns the uppercase equivalent of the byte, or the byte unchanged if it has none.
+    #[inline(always)]
+    pub const fn to_uppercase(self, byte: u8) -> u8 {
+        match self {
+            CaseMode::Ascii7 => byte.to_ascii_uppercase(),
+            CaseMode::Latin1 => LATIN1_UPPER[byte as usize],
+        }
+    }
+    /// Returns the lowercase equivalent of the byte, or the byte unchanged if it has none.
+    #[inline(always)]
+    pub const fn to_lowercase(self, byte: u8) -> u8 {
+        match self {
+            CaseMode::Ascii7 => byte.to_ascii_lowercase(),
+            CaseMode::Latin1 => LATIN1_LOWER[byte as usize],
+        }
+    }
+    /// Returns true if the two bytes are equal, ignoring case.
+    #[inline(always)]
+    pub const fn eq_ignore_case(self, a: u8, b: u8) -> bool {
+        self.to_lowercase(a) == self.to_lowercase(b)
+    }
+    /// Compares two byte slices lexicographically, ignoring case.
+    pub fn cmp_ignore_case(self, a: &[u8], b: &[u8]) -> Ordering {
+        let a = a.iter().map(|byte| self.to_lowercase(*byte));
+        let b = b.iter().map(|byte| self.to_lowercase(*byte));
+        a.cmp(b)
+    }
+}
+
+const fn is_latin1_upper(byte: u8) -> bool {
+    byte.is_ascii_uppercase() || (byte >= 0xC0 && byte <= 0xDE && byte != 0xD7)
+}
+
+const fn is_latin1_lower(byte: u8) -> bool {
+    byte.is_ascii_lowercase() || (byte >= 0xE0 && byte <= 0xFE && byte != 0xF7)
+}
+
+const fn latin1_table(upper: bool) -> [u8; 256] {
+    let mut table = [0u8; 256];
+    let mut inx = 0;
+    while inx < 256 {
+        let byte = inx as u8;
+        // upper and lower case letters are always 0x20 apart.
+        table[inx] = if upper && is_latin1_lower(byte) {
+            byte - 0x20
+        } else if !upper && is_latin1_upper(byte) {
+            byte + 0x20
+        } else {
+            byte
+        };
+        inx += 1;
+    }
+    table
+}
+
+static LATIN1_UPPER: [u8; 256] = latin1_table(true);
+static LATIN1_LOWER: [u8; 256] = latin1_table(false);
+
+macro_rules! case_insensitive {
+    ($name:ident, $mode:expr, $doc:literal) => {
+        #[doc = $doc]
+        /// <br>
+        /// `Eq`, `Ord` and `Hash` all ignore case, so it can be used as a `BTreeMap`/`HashMap` key
+        /// or with `sort`.
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $name<T>(pub T);
+
+        impl<T> $name<T> {
+            /// Returns the wrapped value.
+            #[inline]
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T: AsRef<AsciiStr>> $name<T> {
+            #[inline(always)]
+            fn bytes(&self) -> &[u8] {
+                self.0.as_ref().as_bytes()
+            }
+        }
+
+        impl<T: AsRef<AsciiStr>> PartialEq for $name<T> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                let (a, b) = (self.bytes(), other.bytes());
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| $mode.eq_ignore_case(*a, *b))
+            }
+        }
+
+        impl<T: AsRef<AsciiStr>> Eq for $name<T> {}
+
+        impl<T: AsRef<AsciiStr>> PartialOrd for $name<T> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<T: AsRef<AsciiStr>> Ord for $name<T> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                $mode.cmp_ignore_case(self.bytes(), other.bytes())
+            }
+        }
+
+        impl<T: AsRef<AsciiStr>> Hash for $name<T> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                let bytes = self.bytes();
+                state.write_usize(bytes.len());
+                for byte in bytes {
+                    state.write_u8($mode.to_lowercase(*byte));
+                }
+            }
+        }
+    };
+}
+
+case_insensitive!(
+    CaseInsensitive,
+    CaseMode::Ascii7,
+    "A wrapper that compares `AsciiStr`s (or anything that is `AsRef<AsciiStr>`) ignoring 7-bit ASCII case."
+);
+case_insensitive!(
+    Latin1CaseInsensitive,
+    CaseMode::Latin1,
+    "A wrapper that compares `AsciiStr`s (or anything that is `AsRef<AsciiStr>`) ignoring Latin-1 case (see `CaseMode::Latin1`)."
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ascii_string::AsciiString;
+    use crate::ascii_translators::ascii_ord_to_char;
+    use std::collections::{BTreeSet, HashSet};
+
+    #[test]
+    fn test_latin1_matches_char() {
+        for byte in 0..=255u8 {
+            let c = ascii_ord_to_char(byte);
+            let upper: Vec<char> = c.to_uppercase().collect();
+            let lower: Vec<char> = c.to_lowercase().collect();
+            let expected_upper = match upper[..] {
+                [u] if (u as u32) < 256 => u as u8,
+                _ => byte,
+            };
+            let expected_lower = match lower[..] {
+                [l] if (l as u32) < 256 => l as u8,
+                _ => byte,
+            };
+            assert_eq!(
+                CaseMode::Latin1.to_uppercase(byte),
+                expected_upper,
+                "{byte:#x}"
+            );
+            assert_eq!(
+                CaseMode::Latin1.to_lowercase(byte),
+                expected_lower,
+                "{byte:#x}"
+            );
+            assert_eq!(
+                CaseMode::Ascii7.to_uppercase(byte),
+                byte.to_ascii_uppercase()
+            );
+            assert_eq!(
+                CaseMode::Ascii7.to_lowercase(byte),
+                byte.to_ascii_lowercase()
+            );
+        }
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        for byte in [0xD7, 0xF7, 0xDF, 0xFF, 0xB5] {
+            assert_eq!(CaseMode::Latin1.to_uppercase(byte), byte);
+            assert_eq!(CaseMode::Latin1.to_lowercase(byte), byte);
+        }
+    }
+
+    #[test]
+    fn test_wrappers() {
+        let a = AsciiString::try_from("ÉCOLE").unwrap();
+        let b = AsciiString::try_from("école").unwrap();
+        assert_ne!(CaseInsensitive(&a), CaseInsensitive(&b));
+        assert_eq!(Latin1CaseInsensitive(&a), Latin1CaseInsensitive(&b));
+        let (a_tail, b_tail) = (
+            AsciiStr::from_bytes(&a.as_bytes()[1..]),
+            AsciiStr::from_bytes(&b.as_bytes()[1..]),
+        );
+        assert_eq!(CaseInsensitive(a_tail), CaseInsensitive(b_tail));
+
+        let set: HashSet<_> = [&a, &b].into_iter().map(Latin1CaseInsensitive).collect();
+        assert_eq!(set.len(), 1);
+
+        let words = ["b", "A", "c", "a"].map(|w| AsciiString::try_from(w).unwrap());
+        let mut sorted: Vec<_> = words.iter().map(CaseInsensitive).collect();
+        sorted.sort();
+        let sorted: Vec<String> = sorted.into_iter().map(|w| w.0.to_string()).collect();
+        assert_eq!(sorted, ["A", "a", "b", "c"]);
+        let unique: BTreeSet<_> = words.iter().map(CaseInsensitive).collect();
+        assert_eq!(unique.len(), 3);
+    }
+}
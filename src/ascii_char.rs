@@ -0,0 +1,542 @@
+use crate::ascii_case::CaseMode;
+use crate::ascii_error::AsciiError;
+use crate::ascii_group::AsciiGroup;
+use crate::ascii_traits::{AsciiOrdToChar, CharToAsciiOrd, ToAsciiBytes, BYTES};
+use crate::ascii_translators::*;
+use std::borrow::Cow;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// A single ASCII or Extended ASCII character.
+/// <br>
+/// The enum has one variant for each of the 256 u8 values, named after the constants in `ascii_consts`,
+/// so any byte can be converted to an `AsciiChar` without failing.
+/// # samples
+/// ```
+/// use cj_ascii::prelude::*;
+///
+/// let a = AsciiChar::LATIN_CAPITAL_LETTER_A;
+/// assert_eq!(a.as_byte(), 65);
+/// assert_eq!(a.as_char(), 'A');
+/// assert_eq!(AsciiChar::from_byte(LATIN_CAPITAL_LETTER_A), a);
+/// assert_eq!(AsciiChar::from_char('A'), Some(a));
+/// assert_eq!(AsciiChar::from_char('€'), None);
+/// assert!(a.is_printable());
+///
+/// let mut astring = AsciiString::new();
+/// astring.push(a);
+/// astring.push(AsciiChar::NO_BREAK_SPACE);
+/// assert_eq!(astring.pop_ascii_char(), Some(AsciiChar::NO_BREAK_SPACE));
+/// assert_eq!(astring.get_ascii_char(0), Some(a));
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum AsciiChar {
+    NULL = 0x00,
+    SOH = 0x01,
+    STX = 0x02,
+    ETX = 0x03,
+    EOT = 0x04,
+    ENQ = 0x05,
+    ACK = 0x06,
+    BEL = 0x07,
+    BS = 0x08,
+    HT = 0x09,
+    LF = 0x0A,
+    VT = 0x0B,
+    FF = 0x0C,
+    CR = 0x0D,
+    SO = 0x0E,
+    SI = 0x0F,
+    DLE = 0x10,
+    DC1 = 0x11,
+    DC2 = 0x12,
+    DC3 = 0x13,
+    DC4 = 0x14,
+    NAK = 0x15,
+    SYN = 0x16,
+    ETB = 0x17,
+    CAN = 0x18,
+    EM = 0x19,
+    SUB = 0x1A,
+    ESC = 0x1B,
+    FS = 0x1C,
+    GS = 0x1D,
+    RS = 0x1E,
+    US = 0x1F,
+    SPACE = 0x20,
+    EXCLAMATION_MARK = 0x21,
+    QUOTATION_MARK = 0x22,
+    NUMBER_SIGN = 0x23,
+    DOLLAR_SIGN = 0x24,
+    PERCENT_SIGN = 0x25,
+    AMPERSAND = 0x26,
+    APOSTROPHE = 0x27,
+    LEFT_PARENTHESIS = 0x28,
+    RIGHT_PARENTHESIS = 0x29,
+    ASTERISK = 0x2A,
+    PLUS_SIGN = 0x2B,
+    COMMA = 0x2C,
+    HYPHEN_MINUS = 0x2D,
+    FULL_STOP = 0x2E,
+    SOLIDUS = 0x2F,
+    DIGIT_ZERO = 0x30,
+    DIGIT_ONE = 0x31,
+    DIGIT_TWO = 0x32,
+    DIGIT_THREE = 0x33,
+    DIGIT_FOUR = 0x34,
+    DIGIT_FIVE = 0x35,
+    DIGIT_SIX = 0x36,
+    DIGIT_SEVEN = 0x37,
+    DIGIT_EIGHT = 0x38,
+    DIGIT_NINE = 0x39,
+    COLON = 0x3A,
+    SEMICOLON = 0x3B,
+    LESS_THAN_SIGN = 0x3C,
+    EQUALS_SIGN = 0x3D,
+    GREATER_THAN_SIGN = 0x3E,
+    QUESTION_MARK = 0x3F,
+    COMMERCIAL_AT = 0x40,
+    LATIN_CAPITAL_LETTER_A = 0x41,
+    LATIN_CAPITAL_LETTER_B = 0x42,
+    LATIN_CAPITAL_LETTER_C = 0x43,
+    LATIN_CAPITAL_LETTER_D = 0x44,
+    LATIN_CAPITAL_LETTER_E = 0x45,
+    LATIN_CAPITAL_LETTER_F = 0x46,
+    LATIN_CAPITAL_LETTER_G = 0x47,
+    LATIN_CAPITAL_LETTER_H = 0x48,
+    LATIN_CAPITAL_LETTER_I = 0x49,
+    LATIN_CAPITAL_LETTER_J = 0x4A,
+    LATIN_CAPITAL_LETTER_K = 0x4B,
+    LATIN_CAPITAL_LETTER_L = 0x4C,
+    LATIN_CAPITAL_LETTER_M = 0x4D,
+    LATIN_CAPITAL_LETTER_N = 0x4E,
+    LATIN_CAPITAL_LETTER_O = 0x4F,
+    LATIN_CAPITAL_LETTER_P = 0x50,
+    LATIN_CAPITAL_LETTER_Q = 0x51,
+    LATIN_CAPITAL_LETTER_R = 0x52,
+    LATIN_CAPITAL_LETTER_S = 0x53,
+    LATIN_CAPITAL_LETTER_T = 0x54,
+    LATIN_CAPITAL_LETTER_U = 0x55,
+    LATIN_CAPITAL_LETTER_V = 0x56,
+    LATIN_CAPITAL_LETTER_W = 0x57,
+    LATIN_CAPITAL_LETTER_X = 0x58,
+    LATIN_CAPITAL_LETTER_Y = 0x59,
+    LATIN_CAPITAL_LETTER_Z = 0x5A,
+    LEFT_SQUARE_BRACKET = 0x5B,
+    REVERSE_SOLIDUS = 0x5C,
+    RIGHT_SQUARE_BRACKET = 0x5D,
+    CIRCUMFLEX_ACCENT = 0x5E,
+    LOW_LINE = 0x5F,
+    GRAVE_ACCENT = 0x60,
+    LATIN_SMALL_LETTER_A = 0x61,
+    LATIN_SMALL_LETTER_B = 0x62,
+    LATIN_SMALL_LETTER_C = 0x63,
+    LATIN_SMALL_LETTER_D = 0x64,
+    LATIN_SMALL_LETTER_E = 0x65,
+    LATIN_SMALL_LETTER_F = 0x66,
+    LATIN_SMALL_LETTER_G = 0x67,
+    LATIN_SMALL_LETTER_H = 0x68,
+    LATIN_SMALL_LETTER_I = 0x69,
+    LATIN_SMALL_LETTER_J = 0x6A,
+    LATIN_SMALL_LETTER_K = 0x6B,
+    LATIN_SMALL_LETTER_L = 0x6C,
+    LATIN_SMALL_LETTER_M = 0x6D,
+    LATIN_SMALL_LETTER_N = 0x6E,
+    LATIN_SMALL_LETTER_O = 0x6F,
+    LATIN_SMALL_LETTER_P = 0x70,
+    LATIN_SMALL_LETTER_Q = 0x71,
+    LATIN_SMALL_LETTER_R = 0x72,
+    LATIN_SMALL_LETTER_S = 0x73,
+    LATIN_SMALL_LETTER_T = 0x74,
+    LATIN_SMALL_LETTER_U = 0x75,
+    LATIN_SMALL_LETTER_V = 0x76,
+    LATIN_SMALL_LETTER_W = 0x77,
+    LATIN_SMALL_LETTER_X = 0x78,
+    LATIN_SMALL_LETTER_Y = 0x79,
+    LATIN_SMALL_LETTER_Z = 0x7A,
+    LEFT_CURLY_BRACKET = 0x7B,
+    VERTICAL_LINE = 0x7C,
+    RIGHT_CURLY_BRACKET = 0x7D,
+    TILDE = 0x7E,
+    DEL = 0x7F,
+    EXT_128 = 0x80,
+    EXT_129 = 0x81,
+    EXT_130 = 0x82,
+    EXT_131 = 0x83,
+    EXT_132 = 0x84,
+    EXT_133 = 0x85,
+    EXT_134 = 0x86,
+    EXT_135 = 0x87,
+    EXT_136 = 0x88,
+    EXT_137 = 0x89,
+    EXT_138 = 0x8A,
+    EXT_139 = 0x8B,
+    EXT_140 = 0x8C,
+    EXT_141 = 0x8D,
+    EXT_142 = 0x8E,
+    EXT_143 = 0x8F,
+    EXT_144 = 0x90,
+    EXT_145 = 0x91,
+    EXT_146 = 0x92,
+    EXT_147 = 0x93,
+    EXT_148 = 0x94,
+    EXT_149 = 0x95,
+    EXT_150 = 0x96,
+    EXT_151 = 0x97,
+    EXT_152 = 0x98,
+    EXT_153 = 0x99,
+    EXT_154 = 0x9A,
+    EXT_155 = 0x9B,
+    EXT_156 = 0x9C,
+    EXT_157 = 0x9D,
+    EXT_158 = 0x9E,
+    EXT_159 = 0x9F,
+    NO_BREAK_SPACE = 0xA0,
+    INVERTED_EXCLAMATION_MARK = 0xA1,
+    CENT_SIGN = 0xA2,
+    POUND_SIGN = 0xA3,
+    CURRENCY_SIGN = 0xA4,
+    YEN_SIGN = 0xA5,
+    BROKEN_BAR = 0xA6,
+    SECTION_SIGN = 0xA7,
+    DIAERESIS = 0xA8,
+    COPYRIGHT_SIGN = 0xA9,
+    FEMININE_ORDINAL_INDICATOR = 0xAA,
+    LEFT_POINTING_DOUBLE_ANGLE_QUOTATION_MARK = 0xAB,
+    NOT_SIGN = 0xAC,
+    SHY = 0xAD,
+    REGISTERED_SIGN = 0xAE,
+    MACRON = 0xAF,
+    DEGREE_SIGN = 0xB0,
+    PLUS_MINUS_SIGN = 0xB1,
+    SUPERSCRIPT_TWO = 0xB2,
+    SUPERSCRIPT_THREE = 0xB3,
+    ACUTE_ACCENT = 0xB4,
+    MICRO_SIGN = 0xB5,
+    PILCROW_SIGN = 0xB6,
+    MIDDLE_DOT = 0xB7,
+    CEDILLA = 0xB8,
+    SUPERSCRIPT_ONE = 0xB9,
+    MASCULINE_ORDINAL_INDICATOR = 0xBA,
+    RIGHT_POINTING_DOUBLE_ANGLE_QUOTATION_MARK = 0xBB,
+    VULGAR_FRACTION_ONE_QUARTER = 0xBC,
+    VULGAR_FRACTION_ONE_HALF = 0xBD,
+    VULGAR_FRACTION_THREE_QUARTERS = 0xBE,
+    INVERTED_QUESTION_MARK = 0xBF,
+    LATIN_CAPITAL_LETTER_A_WITH_GRAVE = 0xC0,
+    LATIN_CAPITAL_LETTER_A_WITH_ACUTE = 0xC1,
+    LATIN_CAPITAL_LETTER_A_WITH_CIRCUMFLEX = 0xC2,
+    LATIN_CAPITAL_LETTER_A_WITH_TILDE = 0xC3,
+    LATIN_CAPITAL_LETTER_A_WITH_DIAERESIS = 0xC4,
+    LATIN_CAPITAL_LETTER_A_WITH_RING_ABOVE = 0xC5,
+    LATIN_CAPITAL_LETTER_AE = 0xC6,
+    LATIN_CAPITAL_LETTER_C_WITH_CEDILLA = 0xC7,
+    LATIN_CAPITAL_LETTER_E_WITH_GRAVE = 0xC8,
+    LATIN_CAPITAL_LETTER_E_WITH_ACUTE = 0xC9,
+    LATIN_CAPITAL_LETTER_E_WITH_CIRCUMFLEX = 0xCA,
+    LATIN_CAPITAL_LETTER_E_WITH_DIAERESIS = 0xCB,
+    LATIN_CAPITAL_LETTER_I_WITH_GRAVE = 0xCC,
+    LATIN_CAPITAL_LETTER_I_WITH_ACUTE = 0xCD,
+    LATIN_CAPITAL_LETTER_I_WITH_CIRCUMFLEX = 0xCE,
+    LATIN_CAPITAL_LETTER_I_WITH_DIAERESIS = 0xCF,
+    LATIN_CAPITAL_LETTER_ETH = 0xD0,
+    LATIN_CAPITAL_LETTER_N_WITH_TILDE = 0xD1,
+    LATIN_CAPITAL_LETTER_O_WITH_GRAVE = 0xD2,
+    LATIN_CAPITAL_LETTER_O_WITH_ACUTE = 0xD3,
+    LATIN_CAPITAL_LETTER_O_WITH_CIRCUMFLEX = 0xD4,
+    LATIN_CAPITAL_LETTER_O_WITH_TILDE = 0xD5,
+    LATIN_CAPITAL_LETTER_O_WITH_DIAERESIS = 0xD6,
+    MULTIPLICATION_SIGN = 0xD7,
+    LATIN_CAPITAL_LETTER_O_WITH_STROKE = 0xD8,
+    LATIN_CAPITAL_LETTER_U_WITH_GRAVE = 0xD9,
+    LATIN_CAPITAL_LETTER_U_WITH_ACUTE = 0xDA,
+    LATIN_CAPITAL_LETTER_U_WITH_CIRCUMFLEX = 0xDB,
+    LATIN_CAPITAL_LETTER_U_WITH_DIAERESIS = 0xDC,
+    LATIN_CAPITAL_LETTER_Y_WITH_ACUTE = 0xDD,
+    LATIN_CAPITAL_LETTER_THORN = 0xDE,
+    LATIN_SMALL_LETTER_SHARP_S = 0xDF,
+    LATIN_SMALL_LETTER_A_WITH_GRAVE = 0xE0,
+    LATIN_SMALL_LETTER_A_WITH_ACUTE = 0xE1,
+    LATIN_SMALL_LETTER_A_WITH_CIRCUMFLEX = 0xE2,
+    LATIN_SMALL_LETTER_A_WITH_TILDE = 0xE3,
+    LATIN_SMALL_LETTER_A_WITH_DIAERESIS = 0xE4,
+    LATIN_SMALL_LETTER_A_WITH_RING_ABOVE = 0xE5,
+    LATIN_SMALL_LETTER_AE = 0xE6,
+    LATIN_SMALL_LETTER_C_WITH_CEDILLA = 0xE7,
+    LATIN_SMALL_LETTER_E_WITH_GRAVE = 0xE8,
+    LATIN_SMALL_LETTER_E_WITH_ACUTE = 0xE9,
+    LATIN_SMALL_LETTER_E_WITH_CIRCUMFLEX = 0xEA,
+    LATIN_SMALL_LETTER_E_WITH_DIAERESIS = 0xEB,
+    LATIN_SMALL_LETTER_I_WITH_GRAVE = 0xEC,
+    LATIN_SMALL_LETTER_I_WITH_ACUTE = 0xED,
+    LATIN_SMALL_LETTER_I_WITH_CIRCUMFLEX = 0xEE,
+    LATIN_SMALL_LETTER_I_WITH_DIAERESIS = 0xEF,
+    LATIN_SMALL_LETTER_ETH = 0xF0,
+    LATIN_SMALL_LETTER_N_WITH_TILDE = 0xF1,
+    LATIN_SMALL_LETTER_O_WITH_GRAVE = 0xF2,
+    LATIN_SMALL_LETTER_O_WITH_ACUTE = 0xF3,
+    LATIN_SMALL_LETTER_O_WITH_CIRCUMFLEX = 0xF4,
+    LATIN_SMALL_LETTER_O_WITH_TILDE = 0xF5,
+    LATIN_SMALL_LETTER_O_WITH_DIAERESIS = 0xF6,
+    DIVISION_SIGN = 0xF7,
+    LATIN_SMALL_LETTER_O_WITH_STROKE = 0xF8,
+    LATIN_SMALL_LETTER_U_WITH_GRAVE = 0xF9,
+    LATIN_SMALL_LETTER_U_WITH_ACUTE = 0xFA,
+    LATIN_SMALL_LETTER_U_WITH_CIRCUMFLEX = 0xFB,
+    LATIN_SMALL_LETTER_U_WITH_DIAERESIS = 0xFC,
+    LATIN_SMALL_LETTER_Y_WITH_ACUTE = 0xFD,
+    LATIN_SMALL_LETTER_THORN = 0xFE,
+    LATIN_SMALL_LETTER_Y_WITH_DIAERESIS = 0xFF,
+}
+
+impl AsciiChar {
+    /// Returns the `AsciiChar` for the given ASCII ordinal value.
+    #[inline(always)]
+    pub const fn from_byte(byte: u8) -> Self {
+        // SAFETY: AsciiChar is repr(u8) and has a variant for every u8 value.
+        unsafe { std::mem::transmute::<u8, AsciiChar>(byte) }
+    }
+    /// Returns the `AsciiChar` represented by the character, or `None` if the character is not ASCII.
+    #[inline(always)]
+    pub const fn from_char(character: char) -> Option<Self> {
+        match char_to_ascii_ord(character) {
+            Some(byte) => Some(Self::from_byte(byte)),
+            None => None,
+        }
+    }
+    /// Returns the ASCII ordinal value of the `AsciiChar`.
+    #[inline(always)]
+    pub const fn as_byte(self) -> u8 {
+        self as u8
+    }
+    /// Returns the character represented by the `AsciiChar`.
+    #[inline(always)]
+    pub const fn as_char(self) -> char {
+        ascii_ord_to_char(self as u8)
+    }
+    /// Returns the `AsciiGroup` the `AsciiChar` belongs to.
+    #[inline(always)]
+    pub fn group(self) -> AsciiGroup {
+        AsciiGroup::new(&(self as u8))
+    }
+    /// Returns true if the `AsciiChar` is a non-printable control character (see `AsciiGroup::NonPrintableCtrl`).
+    #[inline(always)]
+    pub const fn is_non_printable_ctrl(self) -> bool {
+        !self.is_printable_ctrl() && !self.is_printable() && !self.is_extended()
+    }
+    /// Returns true if the `AsciiChar` is a printable control character (see `AsciiGroup::PrintableCtrl`).
+    #[inline(always)]
+    pub const fn is_printable_ctrl(self) -> bool {
+        matches!(self, Self::HT | Self::LF | Self::CR)
+    }
+    /// Returns true if the `AsciiChar` is printable, space through tilde (see `AsciiGroup::Printable`).
+    #[inline(always)]
+    pub const fn is_printable(self) -> bool {
+        matches!(self as u8, 0x20..=0x7E)
+    }
+    /// Returns true if the `AsciiChar` is in the extended ascii range (see `AsciiGroup::Extended`).
+    #[inline(always)]
+    pub const fn is_extended(self) -> bool {
+        self as u8 >= 0x80
+    }
+    /// Returns the uppercase equivalent of the `AsciiChar`, or itself if it has none (see `CaseMode`).
+    #[inline(always)]
+    pub const fn to_uppercase(self, mode: CaseMode) -> AsciiChar {
+        Self::from_byte(mode.to_uppercase(self as u8))
+    }
+    /// Returns the lowercase equivalent of the `AsciiChar`, or itself if it has none (see `CaseMode`).
+    #[inline(always)]
+    pub const fn to_lowercase(self, mode: CaseMode) -> AsciiChar {
+        Self::from_byte(mode.to_lowercase(self as u8))
+    }
+    /// Returns true if the `AsciiChar` is whitespace as defined by `char::is_whitespace`
+    /// (HT, LF, VT, FF, CR, space, NEL `0x85` and no-break space `0xA0`).
+    #[inline(always)]
+    pub const fn is_whitespace(self) -> bool {
+        matches!(
+            self,
+            Self::HT
+                | Self::LF
+                | Self::VT
+                | Self::FF
+                | Self::CR
+                | Self::SPACE
+                | Self::EXT_133
+                | Self::NO_BREAK_SPACE
+        )
+    }
+    /// Returns true if the `AsciiChar` is whitespace as defined by `u8::is_ascii_whitespace`
+    /// (HT, LF, FF, CR and space - VT is not included).
+    #[inline(always)]
+    pub const fn is_ascii_whitespace(self) -> bool {
+        matches!(
+            self,
+            Self::HT | Self::LF | Self::FF | Self::CR | Self::SPACE
+        )
+    }
+}
+
+impl From<u8> for AsciiChar {
+    #[inline(always)]
+    fn from(value: u8) -> Self {
+        Self::from_byte(value)
+    }
+}
+
+impl From<&u8> for AsciiChar {
+    #[inline(always)]
+    fn from(value: &u8) -> Self {
+        Self::from_byte(*value)
+    }
+}
+
+impl From<AsciiChar> for u8 {
+    #[inline(always)]
+    fn from(value: AsciiChar) -> Self {
+        value.as_byte()
+    }
+}
+
+impl From<AsciiChar> for char {
+    #[inline(always)]
+    fn from(value: AsciiChar) -> Self {
+        value.as_char()
+    }
+}
+
+impl From<AsciiChar> for AsciiGroup {
+    #[inline(always)]
+    fn from(value: AsciiChar) -> Self {
+        value.group()
+    }
+}
+
+impl TryFrom<char> for AsciiChar {
+    type Error = AsciiError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Self::from_char(value).ok_or(AsciiError::non_ascii(value, 0, 0))
+    }
+}
+
+impl CharToAsciiOrd for AsciiChar {
+    #[inline(always)]
+    fn ascii_ord(&self) -> Option<u8> {
+        Some(self.as_byte())
+    }
+    #[inline(always)]
+    fn try_ascii_ord(&self) -> Result<u8, AsciiError> {
+        Ok(self.as_byte())
+    }
+}
+
+impl CharToAsciiOrd for &AsciiChar {
+    #[inline(always)]
+    fn ascii_ord(&self) -> Option<u8> {
+        Some(self.as_byte())
+    }
+    #[inline(always)]
+    fn try_ascii_ord(&self) -> Result<u8, AsciiError> {
+        Ok(self.as_byte())
+    }
+}
+
+impl ToAsciiBytes for AsciiChar {
+    #[inline(always)]
+    fn try_ascii_bytes(&self) -> Result<Cow<'_, [u8]>, AsciiError> {
+        Ok(Cow::Borrowed(std::slice::from_ref(
+            &BYTES[self.as_byte() as usize],
+        )))
+    }
+}
+
+impl AsciiOrdToChar for AsciiChar {
+    #[inline(always)]
+    fn to_ascii_char(&self) -> char {
+        self.as_char()
+    }
+}
+
+impl Display for AsciiChar {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ascii_consts::*;
+
+    #[test]
+    fn test_round_trip() {
+        for byte in 0..=255u8 {
+            let c = AsciiChar::from_byte(byte);
+            assert_eq!(c.as_byte(), byte);
+            assert_eq!(c.as_char(), ascii_ord_to_char(byte));
+            assert_eq!(AsciiChar::from_char(c.as_char()), Some(c));
+        }
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(AsciiChar::NULL.as_byte(), NULL);
+        assert_eq!(
+            AsciiChar::LATIN_CAPITAL_LETTER_A.as_byte(),
+            LATIN_CAPITAL_LETTER_A
+        );
+        assert_eq!(AsciiChar::DEL.as_byte(), DEL);
+        assert_eq!(AsciiChar::EXT_128.as_byte(), EXT_128);
+        assert_eq!(AsciiChar::NO_BREAK_SPACE.as_char(), '\u{A0}');
+        assert_eq!(
+            AsciiChar::LATIN_SMALL_LETTER_Y_WITH_DIAERESIS.as_char(),
+            '\u{FF}'
+        );
+    }
+
+    #[test]
+    fn test_groups_match_ascii_group() {
+        for byte in 0..=255u8 {
+            let c = AsciiChar::from_byte(byte);
+            let group = AsciiGroup::new(&byte);
+            assert_eq!(c.group(), group);
+            assert_eq!(c.is_non_printable_ctrl(), group.is_non_printable_ctrl());
+            assert_eq!(c.is_printable_ctrl(), group.is_printable_ctrl());
+            assert_eq!(c.is_printable(), group.is_printable());
+            assert_eq!(c.is_extended(), group.is_extended());
+        }
+    }
+
+    #[test]
+    fn test_try_from_char() {
+        assert_eq!(AsciiChar::try_from('~'), Ok(AsciiChar::TILDE));
+        assert!(AsciiChar::try_from('€').is_err());
+    }
+
+    #[test]
+    fn test_whitespace() {
+        for byte in 0..=255u8 {
+            let c = AsciiChar::from_byte(byte);
+            assert_eq!(c.is_whitespace(), c.as_char().is_whitespace());
+            assert_eq!(c.is_whitespace(), ASCII_WHITESPACE.contains(&byte));
+            assert_eq!(c.is_ascii_whitespace(), byte.is_ascii_whitespace());
+            assert_eq!(c.is_ascii_whitespace(), ASCII_7_WHITESPACE.contains(&byte));
+        }
+    }
+
+    #[test]
+    fn test_to_ascii_bytes_borrowed() {
+        for byte in 0..=255u8 {
+            let c = AsciiChar::from_byte(byte);
+            let bytes = c.try_ascii_bytes().unwrap();
+            assert!(matches!(bytes, Cow::Borrowed(_)));
+            assert_eq!(bytes.as_ref(), &[byte]);
+            let ch = c.as_char();
+            let bytes = ch.try_ascii_bytes().unwrap();
+            assert!(matches!(bytes, Cow::Borrowed(_)));
+            assert_eq!(bytes.as_ref(), &[byte]);
+        }
+    }
+}
@@ -0,0 +1,721 @@
+use crate::ascii_error::AsciiError;
+use crate::ascii_str::AsciiStr;
+use crate::ascii_string::AsciiString;
+use std::fmt;
+use std::fmt::{Display, Formatter, Write};
+
+/// A single byte character encoding, mapping each byte to one Unicode character.
+/// <br>
+/// Implemented by `CodePage`. Implement it for your own tables to use them with the codec aware methods:
+/// * `AsciiStr::display_with` and `AsciiStr::to_string_with` (in place of `Display`/`to_string`).
+/// * `AsciiString::try_from_str_with` (in place of `TryFrom<&str>`).
+/// * `AsciiString::push_str_lossy_with` (in place of `push_str_lossy`).
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// // a codec that shows control characters as their Unicode control pictures (␀, ␁...).
+/// struct ControlPictures;
+///
+/// impl SingleByteCodec for ControlPictures {
+///     fn decode(&self, byte: u8) -> char {
+///         match byte {
+///             0x00..=0x1F => char::from_u32(0x2400 + byte as u32).unwrap(),
+///             _ => byte as char,
+///         }
+///     }
+///     fn encode(&self, character: char) -> Option<u8> {
+///         match character as u32 {
+///             0x2400..=0x241F => Some((character as u32 - 0x2400) as u8),
+///             0x00..=0x1F => None,
+///             _ => u8::try_from(character).ok(),
+///         }
+///     }
+/// }
+///
+/// let astring = AsciiString::try_from("a\tb").unwrap();
+/// assert_eq!(astring.to_string_with(ControlPictures), "a␉b");
+/// let round_trip = AsciiString::try_from_str_with("a␉b", ControlPictures).unwrap();
+/// assert_eq!(round_trip, astring);
+/// ```
+pub trait SingleByteCodec {
+    /// Returns the character represented by the byte.
+    fn decode(&self, byte: u8) -> char;
+    /// Returns the byte representing the character, or `None` if the codec has no such character.
+    fn encode(&self, character: char) -> Option<u8>;
+}
+
+impl<C: SingleByteCodec + ?Sized> SingleByteCodec for &C {
+    #[inline(always)]
+    fn decode(&self, byte: u8) -> char {
+        (**self).decode(byte)
+    }
+    #[inline(always)]
+    fn encode(&self, character: char) -> Option<u8> {
+        (**self).encode(character)
+    }
+}
+
+/// The code page used to interpret the bytes of an `AsciiString` as Unicode characters.
+/// <br>
+/// The rest of the crate (`Display`, `TryFrom<&str>`, `push`...) uses `Latin1`, where every byte maps to the
+/// Unicode code point of the same value. The other code pages are used through the `*_with` methods,
+/// such as `AsciiStr::to_string_with` and `AsciiString::try_from_str_with`.
+/// <br>
+/// All of the code pages are single byte (see `SingleByteCodec`), and agree with ASCII for 0x20..=0x7E.
+/// <br>
+/// The ISO-8859 tables are generated at build time from the mapping files in `data/`.
+/// Bytes a part leaves undefined decode to `char::REPLACEMENT_CHARACTER`, and are never produced when encoding.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// let astring = AsciiString::from(vec![0xC9, 0xCD, 0xBB, 0x01]);
+/// assert_eq!(astring.to_string_with(CodePage::Latin1), "ÉÍ»\u{1}");
+/// assert_eq!(astring.to_string_with(CodePage::Cp437), "╔═╗\u{1}");
+/// assert_eq!(astring.to_string_with(CodePage::Cp437Graphic), "╔═╗☺");
+///
+/// let astring = AsciiString::try_from_str_with("└─┘", CodePage::Cp437).unwrap();
+/// assert_eq!(astring.as_bytes(), [0xC0, 0xC4, 0xD9]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum CodePage {
+    /// ISO-8859-1, each byte is the Unicode code point of the same value (0x80..=0x9F are C1 controls).
+    #[default]
+    Latin1,
+    /// IBM PC / DOS code page 437 (box drawing, Greek and math symbols in 0x80..=0xFF).
+    /// <br>
+    /// 0x00..=0x1F and 0x7F are control characters, as in ASCII.
+    Cp437,
+    /// Code page 437 with the graphical interpretation of 0x01..=0x1F and 0x7F (`☺`, `♥`, `♪`, `→`, `⌂`...),
+    /// as displayed by the IBM PC in text mode.
+    /// <br>
+    /// When encoding, the control characters are also accepted, so text containing `\n` still round trips.
+    Cp437Graphic,
+    /// Windows-1252 (Western European), Latin-1 with printable characters (`€`, `‘`, `’`, `“`, `”`, `–`, `—`, `™`...)
+    /// in place of the C1 controls in 0x80..=0x9F.
+    /// <br>
+    /// The five bytes Windows-1252 leaves undefined (0x81, 0x8D, 0x8F, 0x90 and 0x9D) are mapped to the C1 control
+    /// of the same value (the same as Windows and the WHATWG encoding standard), so every byte still round trips.
+    Cp1252,
+    /// ISO-8859-2 (Latin-2, Central European).
+    Iso8859_2,
+    /// ISO-8859-5 (Cyrillic).
+    Iso8859_5,
+    /// ISO-8859-7 (Greek).
+    Iso8859_7,
+    /// ISO-8859-9 (Latin-5, Turkish).
+    Iso8859_9,
+    /// ISO-8859-15 (Latin-9, Latin-1 with `€`, `Š`, `Œ`, `Ÿ`...).
+    Iso8859_15,
+}
+
+impl CodePage {
+    /// Returns the character represented by the byte in this code page.
+    #[inline]
+    pub fn decode(self, byte: u8) -> char {
+        match self {
+            CodePage::Latin1 => byte as char,
+            CodePage::Cp437 => match byte {
+                0x00..=0x7F => byte as char,
+                _ => CP437_HIGH[byte as usize - 0x80],
+            },
+            CodePage::Cp437Graphic => match byte {
+                0x01..=0x1F => CP437_GRAPHIC_LOW[byte as usize - 0x01],
+                0x7F => '\u{2302}',
+                _ => CodePage::Cp437.decode(byte),
+            },
+            CodePage::Cp1252 => match byte {
+                0x80..=0x9F => CP1252_C1[byte as usize - 0x80],
+                _ => byte as char,
+            },
+            CodePage::Iso8859_2 => ISO_8859_2_DECODE[byte as usize],
+            CodePage::Iso8859_5 => ISO_8859_5_DECODE[byte as usize],
+            CodePage::Iso8859_7 => ISO_8859_7_DECODE[byte as usize],
+            CodePage::Iso8859_9 => ISO_8859_9_DECODE[byte as usize],
+            CodePage::Iso8859_15 => ISO_8859_15_DECODE[byte as usize],
+        }
+    }
+    /// Returns the byte representing the character in this code page, or `None` if the code page has no such character.
+    #[inline]
+    pub fn encode(self, character: char) -> Option<u8> {
+        if character.is_ascii() {
+            return Some(character as u8);
+        }
+        match self {
+            CodePage::Latin1 => u8::try_from(character).ok(),
+            CodePage::Cp437 => lookup(&CP437_ENCODE, character),
+            CodePage::Cp437Graphic => lookup(&CP437_ENCODE, character)
+                .or_else(|| lookup(&CP437_GRAPHIC_ENCODE, character)),
+            CodePage::Cp1252 => match character {
+                '\u{A0}'..='\u{FF}' | '\u{81}' | '\u{8D}' | '\u{8F}' | '\u{90}' | '\u{9D}' => {
+                    Some(character as u8)
+                }
+                _ => lookup(&CP1252_ENCODE, character),
+            },
+            CodePage::Iso8859_2 => table_encode(&ISO_8859_2_DECODE, &ISO_8859_2_ENCODE, character),
+            CodePage::Iso8859_5 => table_encode(&ISO_8859_5_DECODE, &ISO_8859_5_ENCODE, character),
+            CodePage::Iso8859_7 => table_encode(&ISO_8859_7_DECODE, &ISO_8859_7_ENCODE, character),
+            CodePage::Iso8859_9 => table_encode(&ISO_8859_9_DECODE, &ISO_8859_9_ENCODE, character),
+            CodePage::Iso8859_15 => {
+                table_encode(&ISO_8859_15_DECODE, &ISO_8859_15_ENCODE, character)
+            }
+        }
+    }
+}
+
+impl SingleByteCodec for CodePage {
+    #[inline(always)]
+    fn decode(&self, byte: u8) -> char {
+        CodePage::decode(*self, byte)
+    }
+    #[inline(always)]
+    fn encode(&self, character: char) -> Option<u8> {
+        CodePage::encode(*self, character)
+    }
+}
+
+#[inline]
+fn lookup(table: &[(char, u8)], character: char) -> Option<u8> {
+    table
+        .binary_search_by_key(&character, |(c, _)| *c)
+        .ok()
+        .map(|inx| table[inx].1)
+}
+
+/// Encodes using a generated table, where `encode` only holds the bytes that don't map to the code point of the same value.
+#[inline]
+fn table_encode(decode: &[char; 256], encode: &[(char, u8)], character: char) -> Option<u8> {
+    match u8::try_from(character) {
+        Ok(byte) if decode[byte as usize] == character => Some(byte),
+        _ => lookup(encode, character),
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/iso8859.rs"));
+
+/// Displays an `AsciiStr` using a codec.
+/// <br>
+/// Created by `AsciiStr::display_with`.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayWith<'a, C> {
+    astr: &'a AsciiStr,
+    codec: C,
+}
+
+impl<'a, C: SingleByteCodec> DisplayWith<'a, C> {
+    pub(crate) fn new(astr: &'a AsciiStr, codec: C) -> Self {
+        Self { astr, codec }
+    }
+}
+
+impl<C: SingleByteCodec> Display for DisplayWith<'_, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for byte in self.astr.iter() {
+            f.write_char(self.codec.decode(*byte))?;
+        }
+        Ok(())
+    }
+}
+
+impl AsciiStr {
+    /// Decodes the `AsciiStr` into a `String`, using the codec (such as a `CodePage`).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::from(vec![0x9B, b'5']);
+    /// assert_eq!(astring.to_string_with(CodePage::Cp437), "¢5");
+    /// ```
+    pub fn to_string_with<C: SingleByteCodec>(&self, codec: C) -> String {
+        let mut result = String::with_capacity(self.len());
+        for byte in self.iter() {
+            result.push(codec.decode(*byte));
+        }
+        result
+    }
+    /// Returns an object that implements `Display`, decoding the `AsciiStr` with the codec (such as a `CodePage`).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::from(vec![0x03, b'2']);
+    /// assert_eq!(format!("[{}]", astring.display_with(CodePage::Cp437Graphic)), "[♥2]");
+    /// ```
+    #[inline]
+    pub fn display_with<C: SingleByteCodec>(&self, codec: C) -> DisplayWith<'_, C> {
+        DisplayWith::new(self, codec)
+    }
+    /// Decodes the `AsciiStr` into a `String` as Windows-1252 (see `CodePage::Cp1252`).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::from(vec![0x93, b'5', 0x80, 0x94]);
+    /// assert_eq!(astring.decode_cp1252(), "“5€”");
+    /// ```
+    #[inline]
+    pub fn decode_cp1252(&self) -> String {
+        self.to_string_with(CodePage::Cp1252)
+    }
+}
+
+impl AsciiString {
+    /// Encodes a string into a new `AsciiString`, using the codec (such as a `CodePage`).
+    /// # Errors
+    /// * if the string contains a character that is not in the codec.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from_str_with("½ ≥ ¼", CodePage::Cp437).unwrap();
+    /// assert_eq!(astring.as_bytes(), [0xAB, b' ', 0xF2, b' ', 0xAC]);
+    ///
+    /// let error = AsciiString::try_from_str_with("€", CodePage::Cp437).unwrap_err();
+    /// assert_eq!(error.kind(), AsciiErrorKind::Unmappable);
+    /// ```
+    pub fn try_from_str_with<C: SingleByteCodec>(
+        string: &str,
+        codec: C,
+    ) -> Result<AsciiString, AsciiError> {
+        let mut result = AsciiString::with_capacity(string.len());
+        result.try_push_str_with(string, codec)?;
+        Ok(result)
+    }
+    /// Encodes a string into a new `AsciiString` as Windows-1252 (see `CodePage::Cp1252`).
+    /// # Errors
+    /// * if the string contains a character that is not in Windows-1252.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::from_str_cp1252("It’s 5€").unwrap();
+    /// assert_eq!(astring.as_bytes(), b"It\x92s 5\x80");
+    /// assert_eq!(astring.decode_cp1252(), "It’s 5€");
+    /// ```
+    #[inline]
+    pub fn from_str_cp1252(string: &str) -> Result<AsciiString, AsciiError> {
+        Self::try_from_str_with(string, CodePage::Cp1252)
+    }
+    /// Encodes a char onto the end of the `AsciiString`, using the codec (such as a `CodePage`).
+    /// # Errors
+    /// * if the char is not in the codec.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::new();
+    /// assert!(astring.try_push('€').is_err());
+    /// astring.try_push_with('€', CodePage::Cp1252).unwrap();
+    /// assert_eq!(astring.as_bytes(), [0x80]);
+    /// ```
+    pub fn try_push_with<C: SingleByteCodec>(
+        &mut self,
+        character: char,
+        codec: C,
+    ) -> Result<(), AsciiError> {
+        let byte = codec.encode(character).ok_or(AsciiError::unmappable(
+            character,
+            self.len(),
+            self.len(),
+        ))?;
+        self.bytes.push(byte);
+        Ok(())
+    }
+    /// Encodes a string onto the end of the `AsciiString`, using the codec (such as a `CodePage`).
+    /// * the `AsciiString` is left unchanged on error.
+    /// # Errors
+    /// * if the string contains a character that is not in the codec.
+    pub fn try_push_str_with<C: SingleByteCodec>(
+        &mut self,
+        string: &str,
+        codec: C,
+    ) -> Result<(), AsciiError> {
+        let start = self.len();
+        self.bytes.reserve(string.len());
+        for (char_index, (byte_index, character)) in string.char_indices().enumerate() {
+            match codec.encode(character) {
+                Some(byte) => self.bytes.push(byte),
+                None => {
+                    self.bytes.truncate(start);
+                    return Err(AsciiError::unmappable(character, char_index, byte_index));
+                }
+            }
+        }
+        Ok(())
+    }
+    /// Encodes a string onto the end of the `AsciiString`, using the codec (such as a `CodePage`),
+    /// replacing characters that are not in the codec with a space.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::new();
+    /// astring.push_str_lossy_with("Ελλάδα €5 ₿", CodePage::Iso8859_7);
+    /// assert_eq!(astring.len(), 11);
+    /// assert_eq!(astring.to_string_with(CodePage::Iso8859_7), "Ελλάδα €5  ");
+    /// ```
+    pub fn push_str_lossy_with<C: SingleByteCodec>(&mut self, string: &str, codec: C) {
+        self.bytes.reserve(string.len());
+        for character in string.chars() {
+            self.bytes.push(codec.encode(character).unwrap_or(32));
+        }
+    }
+}
+
+/// CP437 characters for bytes 0x80..=0xFF.
+static CP437_HIGH: [char; 128] = [
+    '\u{00C7}', '\u{00FC}', '\u{00E9}', '\u{00E2}', '\u{00E4}', '\u{00E0}', '\u{00E5}', '\u{00E7}',
+    '\u{00EA}', '\u{00EB}', '\u{00E8}', '\u{00EF}', '\u{00EE}', '\u{00EC}', '\u{00C4}', '\u{00C5}',
+    '\u{00C9}', '\u{00E6}', '\u{00C6}', '\u{00F4}', '\u{00F6}', '\u{00F2}', '\u{00FB}', '\u{00F9}',
+    '\u{00FF}', '\u{00D6}', '\u{00DC}', '\u{00A2}', '\u{00A3}', '\u{00A5}', '\u{20A7}', '\u{0192}',
+    '\u{00E1}', '\u{00ED}', '\u{00F3}', '\u{00FA}', '\u{00F1}', '\u{00D1}', '\u{00AA}', '\u{00BA}',
+    '\u{00BF}', '\u{2310}', '\u{00AC}', '\u{00BD}', '\u{00BC}', '\u{00A1}', '\u{00AB}', '\u{00BB}',
+    '\u{2591}', '\u{2592}', '\u{2593}', '\u{2502}', '\u{2524}', '\u{2561}', '\u{2562}', '\u{2556}',
+    '\u{2555}', '\u{2563}', '\u{2551}', '\u{2557}', '\u{255D}', '\u{255C}', '\u{255B}', '\u{2510}',
+    '\u{2514}', '\u{2534}', '\u{252C}', '\u{251C}', '\u{2500}', '\u{253C}', '\u{255E}', '\u{255F}',
+    '\u{255A}', '\u{2554}', '\u{2569}', '\u{2566}', '\u{2560}', '\u{2550}', '\u{256C}', '\u{2567}',
+    '\u{2568}', '\u{2564}', '\u{2565}', '\u{2559}', '\u{2558}', '\u{2552}', '\u{2553}', '\u{256B}',
+    '\u{256A}', '\u{2518}', '\u{250C}', '\u{2588}', '\u{2584}', '\u{258C}', '\u{2590}', '\u{2580}',
+    '\u{03B1}', '\u{00DF}', '\u{0393}', '\u{03C0}', '\u{03A3}', '\u{03C3}', '\u{00B5}', '\u{03C4}',
+    '\u{03A6}', '\u{0398}', '\u{03A9}', '\u{03B4}', '\u{221E}', '\u{03C6}', '\u{03B5}', '\u{2229}',
+    '\u{2261}', '\u{00B1}', '\u{2265}', '\u{2264}', '\u{2320}', '\u{2321}', '\u{00F7}', '\u{2248}',
+    '\u{00B0}', '\u{2219}', '\u{00B7}', '\u{221A}', '\u{207F}', '\u{00B2}', '\u{25A0}', '\u{00A0}',
+];
+
+/// CP437 graphical glyphs for bytes 0x01..=0x1F (0x00 is kept as NUL).
+static CP437_GRAPHIC_LOW: [char; 31] = [
+    '\u{263A}', '\u{263B}', '\u{2665}', '\u{2666}', '\u{2663}', '\u{2660}', '\u{2022}', '\u{25D8}',
+    '\u{25CB}', '\u{25D9}', '\u{2642}', '\u{2640}', '\u{266A}', '\u{266B}', '\u{263C}', '\u{25BA}',
+    '\u{25C4}', '\u{2195}', '\u{203C}', '\u{00B6}', '\u{00A7}', '\u{25AC}', '\u{21A8}', '\u{2191}',
+    '\u{2193}', '\u{2192}', '\u{2190}', '\u{221F}', '\u{2194}', '\u{25B2}', '\u{25BC}',
+];
+
+/// (char, byte) pairs for CP437 bytes 0x80..=0xFF, sorted by char for binary search.
+static CP437_ENCODE: [(char, u8); 128] = [
+    ('\u{00A0}', 0xFF),
+    ('\u{00A1}', 0xAD),
+    ('\u{00A2}', 0x9B),
+    ('\u{00A3}', 0x9C),
+    ('\u{00A5}', 0x9D),
+    ('\u{00AA}', 0xA6),
+    ('\u{00AB}', 0xAE),
+    ('\u{00AC}', 0xAA),
+    ('\u{00B0}', 0xF8),
+    ('\u{00B1}', 0xF1),
+    ('\u{00B2}', 0xFD),
+    ('\u{00B5}', 0xE6),
+    ('\u{00B7}', 0xFA),
+    ('\u{00BA}', 0xA7),
+    ('\u{00BB}', 0xAF),
+    ('\u{00BC}', 0xAC),
+    ('\u{00BD}', 0xAB),
+    ('\u{00BF}', 0xA8),
+    ('\u{00C4}', 0x8E),
+    ('\u{00C5}', 0x8F),
+    ('\u{00C6}', 0x92),
+    ('\u{00C7}', 0x80),
+    ('\u{00C9}', 0x90),
+    ('\u{00D1}', 0xA5),
+    ('\u{00D6}', 0x99),
+    ('\u{00DC}', 0x9A),
+    ('\u{00DF}', 0xE1),
+    ('\u{00E0}', 0x85),
+    ('\u{00E1}', 0xA0),
+    ('\u{00E2}', 0x83),
+    ('\u{00E4}', 0x84),
+    ('\u{00E5}', 0x86),
+    ('\u{00E6}', 0x91),
+    ('\u{00E7}', 0x87),
+    ('\u{00E8}', 0x8A),
+    ('\u{00E9}', 0x82),
+    ('\u{00EA}', 0x88),
+    ('\u{00EB}', 0x89),
+    ('\u{00EC}', 0x8D),
+    ('\u{00ED}', 0xA1),
+    ('\u{00EE}', 0x8C),
+    ('\u{00EF}', 0x8B),
+    ('\u{00F1}', 0xA4),
+    ('\u{00F2}', 0x95),
+    ('\u{00F3}', 0xA2),
+    ('\u{00F4}', 0x93),
+    ('\u{00F6}', 0x94),
+    ('\u{00F7}', 0xF6),
+    ('\u{00F9}', 0x97),
+    ('\u{00FA}', 0xA3),
+    ('\u{00FB}', 0x96),
+    ('\u{00FC}', 0x81),
+    ('\u{00FF}', 0x98),
+    ('\u{0192}', 0x9F),
+    ('\u{0393}', 0xE2),
+    ('\u{0398}', 0xE9),
+    ('\u{03A3}', 0xE4),
+    ('\u{03A6}', 0xE8),
+    ('\u{03A9}', 0xEA),
+    ('\u{03B1}', 0xE0),
+    ('\u{03B4}', 0xEB),
+    ('\u{03B5}', 0xEE),
+    ('\u{03C0}', 0xE3),
+    ('\u{03C3}', 0xE5),
+    ('\u{03C4}', 0xE7),
+    ('\u{03C6}', 0xED),
+    ('\u{207F}', 0xFC),
+    ('\u{20A7}', 0x9E),
+    ('\u{2219}', 0xF9),
+    ('\u{221A}', 0xFB),
+    ('\u{221E}', 0xEC),
+    ('\u{2229}', 0xEF),
+    ('\u{2248}', 0xF7),
+    ('\u{2261}', 0xF0),
+    ('\u{2264}', 0xF3),
+    ('\u{2265}', 0xF2),
+    ('\u{2310}', 0xA9),
+    ('\u{2320}', 0xF4),
+    ('\u{2321}', 0xF5),
+    ('\u{2500}', 0xC4),
+    ('\u{2502}', 0xB3),
+    ('\u{250C}', 0xDA),
+    ('\u{2510}', 0xBF),
+    ('\u{2514}', 0xC0),
+    ('\u{2518}', 0xD9),
+    ('\u{251C}', 0xC3),
+    ('\u{2524}', 0xB4),
+    ('\u{252C}', 0xC2),
+    ('\u{2534}', 0xC1),
+    ('\u{253C}', 0xC5),
+    ('\u{2550}', 0xCD),
+    ('\u{2551}', 0xBA),
+    ('\u{2552}', 0xD5),
+    ('\u{2553}', 0xD6),
+    ('\u{2554}', 0xC9),
+    ('\u{2555}', 0xB8),
+    ('\u{2556}', 0xB7),
+    ('\u{2557}', 0xBB),
+    ('\u{2558}', 0xD4),
+    ('\u{2559}', 0xD3),
+    ('\u{255A}', 0xC8),
+    ('\u{255B}', 0xBE),
+    ('\u{255C}', 0xBD),
+    ('\u{255D}', 0xBC),
+    ('\u{255E}', 0xC6),
+    ('\u{255F}', 0xC7),
+    ('\u{2560}', 0xCC),
+    ('\u{2561}', 0xB5),
+    ('\u{2562}', 0xB6),
+    ('\u{2563}', 0xB9),
+    ('\u{2564}', 0xD1),
+    ('\u{2565}', 0xD2),
+    ('\u{2566}', 0xCB),
+    ('\u{2567}', 0xCF),
+    ('\u{2568}', 0xD0),
+    ('\u{2569}', 0xCA),
+    ('\u{256A}', 0xD8),
+    ('\u{256B}', 0xD7),
+    ('\u{256C}', 0xCE),
+    ('\u{2580}', 0xDF),
+    ('\u{2584}', 0xDC),
+    ('\u{2588}', 0xDB),
+    ('\u{258C}', 0xDD),
+    ('\u{2590}', 0xDE),
+    ('\u{2591}', 0xB0),
+    ('\u{2592}', 0xB1),
+    ('\u{2593}', 0xB2),
+    ('\u{25A0}', 0xFE),
+];
+
+/// (char, byte) pairs for the CP437 graphical glyphs (0x01..=0x1F and 0x7F), sorted by char.
+static CP437_GRAPHIC_ENCODE: [(char, u8); 32] = [
+    ('\u{00A7}', 0x15),
+    ('\u{00B6}', 0x14),
+    ('\u{2022}', 0x07),
+    ('\u{203C}', 0x13),
+    ('\u{2190}', 0x1B),
+    ('\u{2191}', 0x18),
+    ('\u{2192}', 0x1A),
+    ('\u{2193}', 0x19),
+    ('\u{2194}', 0x1D),
+    ('\u{2195}', 0x12),
+    ('\u{21A8}', 0x17),
+    ('\u{221F}', 0x1C),
+    ('\u{2302}', 0x7F),
+    ('\u{25AC}', 0x16),
+    ('\u{25B2}', 0x1E),
+    ('\u{25BA}', 0x10),
+    ('\u{25BC}', 0x1F),
+    ('\u{25C4}', 0x11),
+    ('\u{25CB}', 0x09),
+    ('\u{25D8}', 0x08),
+    ('\u{25D9}', 0x0A),
+    ('\u{263A}', 0x01),
+    ('\u{263B}', 0x02),
+    ('\u{263C}', 0x0F),
+    ('\u{2640}', 0x0C),
+    ('\u{2642}', 0x0B),
+    ('\u{2660}', 0x06),
+    ('\u{2663}', 0x05),
+    ('\u{2665}', 0x03),
+    ('\u{2666}', 0x04),
+    ('\u{266A}', 0x0D),
+    ('\u{266B}', 0x0E),
+];
+
+/// CP1252 characters for bytes 0x80..=0x9F (0xA0..=0xFF are the same as Latin-1).
+/// The five undefined bytes (0x81, 0x8D, 0x8F, 0x90 and 0x9D) map to the C1 control of the same value.
+static CP1252_C1: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+/// (char, byte) pairs for the CP1252 characters in 0x80..=0x9F that are not Latin-1, sorted by char.
+static CP1252_ENCODE: [(char, u8); 27] = [
+    ('\u{0152}', 0x8C),
+    ('\u{0153}', 0x9C),
+    ('\u{0160}', 0x8A),
+    ('\u{0161}', 0x9A),
+    ('\u{0178}', 0x9F),
+    ('\u{017D}', 0x8E),
+    ('\u{017E}', 0x9E),
+    ('\u{0192}', 0x83),
+    ('\u{02C6}', 0x88),
+    ('\u{02DC}', 0x98),
+    ('\u{2013}', 0x96),
+    ('\u{2014}', 0x97),
+    ('\u{2018}', 0x91),
+    ('\u{2019}', 0x92),
+    ('\u{201A}', 0x82),
+    ('\u{201C}', 0x93),
+    ('\u{201D}', 0x94),
+    ('\u{201E}', 0x84),
+    ('\u{2020}', 0x86),
+    ('\u{2021}', 0x87),
+    ('\u{2022}', 0x95),
+    ('\u{2026}', 0x85),
+    ('\u{2030}', 0x89),
+    ('\u{2039}', 0x8B),
+    ('\u{203A}', 0x9B),
+    ('\u{20AC}', 0x80),
+    ('\u{2122}', 0x99),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CODE_PAGES: [CodePage; 9] = [
+        CodePage::Latin1,
+        CodePage::Cp437,
+        CodePage::Cp437Graphic,
+        CodePage::Cp1252,
+        CodePage::Iso8859_2,
+        CodePage::Iso8859_5,
+        CodePage::Iso8859_7,
+        CodePage::Iso8859_9,
+        CodePage::Iso8859_15,
+    ];
+
+    #[test]
+    fn test_round_trip() {
+        for code_page in CODE_PAGES {
+            for byte in 0..=255u8 {
+                let character = code_page.decode(byte);
+                if character == char::REPLACEMENT_CHARACTER {
+                    continue;
+                }
+                assert_eq!(
+                    code_page.encode(character),
+                    Some(byte),
+                    "{code_page:?} {byte:#x}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_latin1_matches_default() {
+        for byte in 0..=255u8 {
+            let astring = AsciiString::from(vec![byte]);
+            assert_eq!(
+                astring.to_string_with(CodePage::Latin1),
+                astring.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_cp437_graphic() {
+        let astring = AsciiString::from(vec![0x00, 0x01, 0x0A, 0x1F, 0x7F]);
+        assert_eq!(
+            astring.to_string_with(CodePage::Cp437),
+            "\0\u{1}\n\u{1F}\u{7F}"
+        );
+        assert_eq!(astring.to_string_with(CodePage::Cp437Graphic), "\0☺◙▼⌂");
+        // controls are accepted when encoding, along with their glyphs.
+        assert_eq!(CodePage::Cp437Graphic.encode('\n'), Some(0x0A));
+        assert_eq!(CodePage::Cp437Graphic.encode('◙'), Some(0x0A));
+        assert_eq!(CodePage::Cp437.encode('◙'), None);
+    }
+
+    #[test]
+    fn test_try_from_str_with_error() {
+        let mut astring = AsciiString::try_from("ok").unwrap();
+        let error = astring
+            .try_push_str_with("╔═é€╗", CodePage::Cp437)
+            .unwrap_err();
+        assert_eq!(error.character(), '€');
+        assert_eq!(error.char_index(), 3);
+        assert_eq!(error.byte_index(), 8);
+        assert_eq!(astring.to_string(), "ok");
+        assert!(AsciiString::try_from_str_with("╔", CodePage::Latin1).is_err());
+    }
+
+    #[test]
+    fn test_cp1252() {
+        let string = "“Smart” ‘quotes’ – €5 — Œuvre™ Ÿ";
+        let astring = AsciiString::from_str_cp1252(string).unwrap();
+        assert_eq!(astring.len(), string.chars().count());
+        assert_eq!(astring.decode_cp1252(), string);
+        assert_eq!(astring.display_with(CodePage::Cp1252).to_string(), string);
+
+        // undefined bytes decode to C1 controls, other C1 controls are not in the code page.
+        let astring = AsciiString::from(vec![0x81, 0x8D, 0x8F, 0x90, 0x9D]);
+        assert_eq!(astring.decode_cp1252(), "\u{81}\u{8D}\u{8F}\u{90}\u{9D}");
+        assert_eq!(CodePage::Cp1252.encode('\u{80}'), None);
+        assert_eq!(CodePage::Cp1252.encode('é'), Some(0xE9));
+        let error = AsciiString::from_str_cp1252("ok\u{85}").unwrap_err();
+        assert_eq!(error.kind(), crate::ascii_error::AsciiErrorKind::Unmappable);
+    }
+
+    #[test]
+    fn test_iso8859() {
+        let samples = [
+            (CodePage::Iso8859_2, "Zażółć gęślą jaźń"),
+            (CodePage::Iso8859_5, "Съешь же ещё этих булок"),
+            (CodePage::Iso8859_7, "Ξεσκεπάζω την ψυχοφθόρα"),
+            (CodePage::Iso8859_9, "Pijamalı hasta yağız şoföre"),
+            (CodePage::Iso8859_15, "Œuvre à 5€ pour Ÿves"),
+        ];
+        for (code_page, sample) in samples {
+            let astring = AsciiString::try_from_str_with(sample, code_page).unwrap();
+            assert_eq!(astring.len(), sample.chars().count());
+            assert_eq!(astring.to_string_with(code_page), sample);
+            // none of the samples are Latin-1.
+            assert!(AsciiString::try_from(sample).is_err());
+        }
+        // undefined in ISO-8859-7.
+        for byte in [0xAE, 0xD2, 0xFF] {
+            assert_eq!(
+                CodePage::Iso8859_7.decode(byte),
+                char::REPLACEMENT_CHARACTER
+            );
+        }
+        assert_eq!(
+            CodePage::Iso8859_7.encode(char::REPLACEMENT_CHARACTER),
+            None
+        );
+        // ISO-8859-15 replaced ¤ with €.
+        assert_eq!(CodePage::Iso8859_15.encode('€'), Some(0xA4));
+        assert_eq!(CodePage::Iso8859_15.encode('¤'), None);
+    }
+
+    #[test]
+    fn test_codec_by_reference() {
+        let code_page = CodePage::Cp437;
+        let codec: &dyn SingleByteCodec = &code_page;
+        let astring = AsciiString::try_from_str_with("╬", codec).unwrap();
+        assert_eq!(astring.display_with(&code_page).to_string(), "╬");
+    }
+}
@@ -1,3 +1,10 @@
+use crate::ascii_consts::{CR, LF};
+use crate::ascii_pattern::memchr;
+use crate::ascii_string::AsciiString;
+
+/// The default buffer capacity of the stream readers and writers (8KB, the same as std and futures).
+pub(crate) const DEFAULT_BUF_SIZE: usize = 8 * 1024;
+
 /// The result of a call to `AsciiStreamReader::read_line()`.
 #[derive(Debug)]
 pub enum ReadLineResult {
@@ -7,6 +14,10 @@ pub enum ReadLineResult {
     EOF,
     /// An error occurred.
     Error(std::io::Error),
+    /// The line is longer than the reader's max_line_len.
+    /// * the first max_line_len bytes (the value) are pushed to the result buf.
+    /// * the rest of the line is skipped by the next read_line(), which returns the line after it.
+    TooLong(usize),
 }
 
 impl ReadLineResult {
@@ -25,20 +36,320 @@ impl ReadLineResult {
     pub fn is_error(&self) -> bool {
         matches!(self, Self::Error(_))
     }
+    /// Returns true if the result is `TooLong`.
+    #[inline(always)]
+    pub fn is_too_long(&self) -> bool {
+        matches!(self, Self::TooLong(_))
+    }
     /// Returns the number of bytes read.
     /// # Panics
-    /// Panics if the result is `EOF` or `Error`.
+    /// Panics if the result is `EOF`, `Error` or `TooLong`.
     #[inline(always)]
     pub fn unwrap(self) -> usize {
         match self {
             Self::Success(v) => v,
             Self::EOF => panic!("Called unwrap on EOF"),
             Self::Error(e) => panic!("Called unwrap on Error: {}", e),
+            Self::TooLong(v) => panic!("Called unwrap on TooLong({})", v),
+        }
+    }
+    /// Turns the result into a lines() item: Some(Ok(line)) for a line, None at EOF.
+    pub(crate) fn into_item<T>(self, line: impl FnOnce() -> T) -> Option<std::io::Result<T>> {
+        match self {
+            Self::Success(_) => Some(Ok(line())),
+            Self::EOF => None,
+            Self::Error(err) => Some(Err(err)),
+            Self::TooLong(max_line_len) => Some(Err(line_too_long(max_line_len))),
+        }
+    }
+}
+
+/// The line terminator(s) recognized by `read_line` and written by `write_line`,
+/// for both the sync and async stream readers and writers.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// # use std::io::Cursor;
+/// // old Mac files end lines with a bare CR.
+/// let mut reader = AsciiStreamReader::new(Cursor::new(b"one\rtwo\r"));
+/// reader.set_line_ending(LineEnding::Cr);
+/// let mut line = AsciiString::new();
+/// assert!(reader.read_line(&mut line).is_success());
+/// assert_eq!(line.to_string(), "one");
+/// assert_eq!(reader.last_terminator(), Some(LineTerminator::Cr));
+///
+/// // NEL (0x85) and RS (0x1E) terminated records.
+/// let mut reader = AsciiStreamReader::new(Cursor::new(b"one\x85two\x1ethree"));
+/// reader.set_line_ending(LineEnding::custom(&[EXT_133, RS]));
+/// let lines: Vec<String> = reader.lines().map(|line| line.unwrap().to_string()).collect();
+/// assert_eq!(lines, ["one", "two", "three"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineEnding {
+    /// Lines end with LF; a CR right before the LF is part of the terminator.
+    /// <br>
+    /// Writes LF. This is the default, matching the original `read_line`/`write_line` behavior.
+    #[default]
+    Lf,
+    /// Lines end with CR LF only; a lone LF or CR is part of the line.
+    /// <br>
+    /// Writes CR LF.
+    CrLf,
+    /// Lines end with CR (classic Mac OS).
+    /// <br>
+    /// Writes CR.
+    Cr,
+    /// Lines end with any of LF, CR LF or CR.
+    /// <br>
+    /// Writes LF.
+    Any,
+    /// Lines end with any byte in the set (see `LineEnding::custom`).
+    /// <br>
+    /// Writes the set's first byte.
+    Custom(TerminatorSet),
+}
+
+impl LineEnding {
+    /// Creates a `LineEnding::Custom` ending lines at any of the specified bytes.
+    /// * each byte is a terminator on its own (a CR followed by an LF is two line ends).
+    /// * `write_line` writes the first byte.
+    /// # Panics
+    /// Panics if bytes is empty.
+    pub const fn custom(bytes: &[u8]) -> Self {
+        Self::Custom(TerminatorSet::new(bytes))
+    }
+    /// Returns the bytes `write_line` writes after each line.
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Lf | Self::Any => &[LF],
+            Self::CrLf => &[CR, LF],
+            Self::Cr => &[CR],
+            Self::Custom(set) => std::slice::from_ref(&set.first),
         }
     }
+    /// Returns the position of the first byte in haystack that may end a line.
+    #[inline]
+    fn find(&self, haystack: &[u8]) -> Option<usize> {
+        match self {
+            Self::Lf | Self::CrLf => memchr(LF, haystack),
+            Self::Cr => memchr(CR, haystack),
+            Self::Any => haystack.iter().position(|&b| b == LF || b == CR),
+            Self::Custom(set) => haystack.iter().position(|&b| set.contains(b)),
+        }
+    }
+}
+
+/// A set of bytes, each of which ends a line (see `LineEnding::custom`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TerminatorSet {
+    bits: [u64; 4],
+    first: u8,
+}
+
+impl TerminatorSet {
+    /// Creates a set of the specified bytes.
+    /// # Panics
+    /// Panics if bytes is empty.
+    pub const fn new(bytes: &[u8]) -> Self {
+        assert!(
+            !bytes.is_empty(),
+            "TerminatorSet requires at least one byte"
+        );
+        let mut bits = [0u64; 4];
+        let mut inx = 0;
+        while inx < bytes.len() {
+            let byte = bytes[inx] as usize;
+            bits[byte >> 6] |= 1 << (byte & 63);
+            inx += 1;
+        }
+        Self {
+            bits,
+            first: bytes[0],
+        }
+    }
+    /// Returns true if the byte is in the set.
+    #[inline]
+    pub const fn contains(&self, byte: u8) -> bool {
+        self.bits[(byte >> 6) as usize] & (1 << (byte & 63)) != 0
+    }
+}
+
+/// The terminator that ended a line (see `AsciiStreamReader::last_terminator`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineTerminator {
+    /// LF (0x0A).
+    Lf,
+    /// CR LF (0x0D 0x0A).
+    CrLf,
+    /// CR (0x0D).
+    Cr,
+    /// Any other byte from a `LineEnding::Custom` set.
+    Byte(u8),
+}
+
+impl LineTerminator {
+    /// Returns the terminator as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Lf => &[LF],
+            Self::CrLf => &[CR, LF],
+            Self::Cr => &[CR],
+            Self::Byte(byte) => std::slice::from_ref(byte),
+        }
+    }
+    #[inline]
+    const fn from_byte(byte: u8) -> Self {
+        match byte {
+            LF => Self::Lf,
+            CR => Self::Cr,
+            _ => Self::Byte(byte),
+        }
+    }
+}
+
+/// The error returned when a line is longer than the reader's max_line_len.
+pub(crate) fn line_too_long(max_line_len: usize) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("line longer than max_line_len ({max_line_len} bytes)"),
+    )
+}
+
+/// The read_line settings and line splitting state shared by the sync and async readers,
+/// fed one buffer at a time.
+#[derive(Debug, Default)]
+pub(crate) struct LineScanner {
+    pub(crate) ending: LineEnding,
+    pub(crate) keep_terminator: bool,
+    pub(crate) max_line_len: Option<usize>,
+    /// a CR right before a Custom terminator is part of the terminator (the EBCDIC reader's CR NL).
+    pub(crate) cr_before_custom: bool,
+    /// the Custom terminator of the line was preceded by a CR (see cr_before_custom).
+    after_cr: bool,
+    /// an Any line ended with CR; the next byte decides between CR and CR LF.
+    pending_cr: bool,
+    /// the rest of a line that was too long is being skipped.
+    discarding: bool,
+    /// the number of bytes read for the current line.
+    read: usize,
+    /// the terminator of the line, once it is complete (None when it ended at EOF).
+    pub(crate) terminator: Option<LineTerminator>,
+}
+
+impl LineScanner {
+    /// Starts reading a new line into buf.
+    pub(crate) fn start(&mut self, buf: &mut AsciiString) {
+        buf.clear();
+        self.read = 0;
+    }
+    /// Feeds the reader's available bytes (an empty available means EOF), pushing the line to buf.
+    /// <br>
+    /// Returns the number of bytes used, and the result once the line is complete.
+    pub(crate) fn feed(
+        &mut self,
+        available: &[u8],
+        buf: &mut AsciiString,
+    ) -> (usize, Option<ReadLineResult>) {
+        let (used, done) = self.scan(available, buf);
+        self.read += used;
+        if self.discarding {
+            if done {
+                self.discarding = false;
+                self.read = 0;
+                buf.clear();
+            } else if let Some(&last) = buf.as_bytes().last() {
+                // keep the last byte, CrLf needs it to tell a CR LF from a lone LF.
+                buf.clear();
+                buf.push(last);
+            }
+            return (used, None);
+        }
+        if let Some(max_line_len) = self.max_line_len {
+            let terminator_len = if done {
+                self.terminator_len()
+            } else {
+                // a trailing CR may turn out to be (part of) the terminator.
+                usize::from(buf.as_bytes().last() == Some(&CR))
+            };
+            if buf.len() - terminator_len > max_line_len {
+                buf.truncate(max_line_len);
+                self.discarding = !done;
+                self.read = 0;
+                return (used, Some(ReadLineResult::TooLong(max_line_len)));
+            }
+        }
+        if !done {
+            return (used, None);
+        }
+        if std::mem::take(&mut self.read) == 0 {
+            return (used, Some(ReadLineResult::EOF));
+        }
+        if !self.keep_terminator {
+            buf.truncate(buf.len() - self.terminator_len());
+        }
+        (used, Some(ReadLineResult::Success(buf.len())))
+    }
+    /// Ends the current line with an error.
+    pub(crate) fn error(&mut self, err: std::io::Error) -> ReadLineResult {
+        self.read = 0;
+        if !self.discarding {
+            self.pending_cr = false;
+        }
+        ReadLineResult::Error(err)
+    }
+    /// Returns the number of bytes of the terminator at the end of a complete line.
+    fn terminator_len(&self) -> usize {
+        self.terminator.map_or(0, |terminator| {
+            terminator.as_bytes().len() + usize::from(self.after_cr)
+        })
+    }
+    /// Pushes the bytes of the current line from available to buf.
+    /// <br>
+    /// Returns the number of bytes used, and whether the line is complete.
+    fn scan(&mut self, available: &[u8], buf: &mut AsciiString) -> (usize, bool) {
+        self.after_cr = false;
+        if self.pending_cr {
+            self.pending_cr = false;
+            return if available.first() == Some(&LF) {
+                buf.push(LF);
+                self.terminator = Some(LineTerminator::CrLf);
+                (1, true)
+            } else {
+                self.terminator = Some(LineTerminator::Cr);
+                (0, true)
+            };
+        }
+        let Some(inx) = self.ending.find(available) else {
+            buf.push_bytes(available);
+            self.terminator = None;
+            return (available.len(), available.is_empty());
+        };
+        buf.push_bytes(&available[..=inx]);
+        let byte = available[inx];
+        let after_cr = buf.len() > 1 && buf[buf.len() - 2] == CR;
+        self.terminator = match self.ending {
+            LineEnding::Lf | LineEnding::CrLf if after_cr => Some(LineTerminator::CrLf),
+            LineEnding::CrLf => return (inx + 1, false),
+            LineEnding::Any if byte == CR => {
+                self.pending_cr = true;
+                return (inx + 1, false);
+            }
+            LineEnding::Custom(_) if self.cr_before_custom && after_cr && byte != CR => {
+                if byte == LF {
+                    Some(LineTerminator::CrLf)
+                } else {
+                    self.after_cr = true;
+                    Some(LineTerminator::Byte(byte))
+                }
+            }
+            _ => Some(LineTerminator::from_byte(byte)),
+        };
+        (inx + 1, true)
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::io_other_error)]
 mod test {
     #[test]
     fn test_is_success() {
@@ -49,6 +360,7 @@ mod test {
             !ReadLineResult::Error(std::io::Error::new(std::io::ErrorKind::Other, "test"))
                 .is_success()
         );
+        assert!(!ReadLineResult::TooLong(0).is_success());
     }
     #[test]
     fn test_is_eof() {
@@ -58,6 +370,7 @@ mod test {
         assert!(
             !ReadLineResult::Error(std::io::Error::new(std::io::ErrorKind::Other, "test")).is_eof()
         );
+        assert!(!ReadLineResult::TooLong(0).is_eof());
     }
     #[test]
     fn test_is_error() {
@@ -68,5 +381,17 @@ mod test {
             ReadLineResult::Error(std::io::Error::new(std::io::ErrorKind::Other, "test"))
                 .is_error()
         );
+        assert!(!ReadLineResult::TooLong(0).is_error());
+    }
+    #[test]
+    fn test_is_too_long() {
+        use super::ReadLineResult;
+        assert!(!ReadLineResult::Success(0).is_too_long());
+        assert!(!ReadLineResult::EOF.is_too_long());
+        assert!(
+            !ReadLineResult::Error(std::io::Error::new(std::io::ErrorKind::Other, "test"))
+                .is_too_long()
+        );
+        assert!(ReadLineResult::TooLong(0).is_too_long());
     }
 }
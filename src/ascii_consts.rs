@@ -268,3 +268,7 @@ pub const ASCII_CTRL_PRINTABLES: [u8; 3] = [HT, LF, CR];
 pub const ASCII_PRINTABLE_RANGE: RangeInclusive<u8> = SPACE..=TILDE;
 // DEL is not included in ASCII_PRINTABLE_RANGE
 pub const ASCII_EXTENDED_RANGE: RangeInclusive<u8> = EXT_128..=LATIN_SMALL_LETTER_Y_WITH_DIAERESIS;
+// whitespace as defined by char::is_whitespace, limited to the ASCII/Extended ASCII range.
+pub const ASCII_WHITESPACE: [u8; 8] = [HT, LF, VT, FF, CR, SPACE, EXT_133, NO_BREAK_SPACE];
+// whitespace as defined by u8::is_ascii_whitespace (VT is not included).
+pub const ASCII_7_WHITESPACE: [u8; 5] = [HT, LF, FF, CR, SPACE];
@@ -0,0 +1,358 @@
+use crate::ascii_char::AsciiChar;
+use crate::ascii_error::AsciiError;
+use crate::ascii_str::AsciiStr;
+use crate::ascii_string::AsciiString;
+use crate::ascii_traits::{AsciiOrdToChar, CharToAsciiOrd};
+use crate::ascii_translators::{utf8_to_latin1, write_latin1};
+use std::collections::vec_deque::Iter;
+use std::collections::VecDeque;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::{Read, Write};
+
+/// A queue of ASCII and Extended ASCII characters, backed by a `VecDeque<u8>`.
+/// <br>
+/// `AsciiString` is contiguous (a `Vec<u8>`), so its front operations shift every remaining byte.
+/// Use `AsciiDeque` where bytes are pushed at one end and consumed at the other (buffers, parsers):
+/// * push/pop at either end are O(1), and reading drains from the front without moving the rest.
+/// * converting to and from an `AsciiString` reuses the allocation
+///   (`From<AsciiString>` is O(1), `From<AsciiDeque>` only moves bytes if the deque has wrapped).
+/// * `make_contiguous()` borrows the contents as an `AsciiStr`, for searching, splitting etc.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// let mut deque = AsciiDeque::new();
+/// deque.push_str("BC");
+/// deque.push_front('A');
+/// deque.push(b'D');
+/// assert_eq!(deque.pop_front_char(), Some('A'));
+/// assert_eq!(deque.pop_char(), Some('D'));
+/// assert_eq!(deque.to_string(), "BC");
+///
+/// let astring = AsciiString::from(deque);
+/// assert_eq!(astring.to_string(), "BC");
+/// let mut deque = AsciiDeque::from(astring);
+/// assert!(deque.make_contiguous().starts_with("B"));
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct AsciiDeque {
+    bytes: VecDeque<u8>,
+}
+
+impl AsciiDeque {
+    /// Creates a new empty `AsciiDeque`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Creates a new empty `AsciiDeque` with the given capacity.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bytes: VecDeque::with_capacity(capacity),
+        }
+    }
+    /// Returns the number of bytes in the `AsciiDeque`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+    /// Returns true if the `AsciiDeque` is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+    /// Returns the capacity of the `AsciiDeque`.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.bytes.capacity()
+    }
+    /// Removes all contents.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+    /// Pushes a char, byte or `AsciiChar` onto the end of the `AsciiDeque`.
+    /// # Panics
+    /// * If a char is supplied and is not ASCII/Extended ASCII.
+    #[inline]
+    pub fn push<T: CharToAsciiOrd>(&mut self, value: T) {
+        self.bytes.push_back(value.ascii_ord_unchecked());
+    }
+    /// Pushes a char, byte or `AsciiChar` onto the end of the `AsciiDeque`.
+    /// # Errors
+    /// * If a char is supplied and is not ASCII/Extended ASCII.
+    pub fn try_push<T: CharToAsciiOrd>(&mut self, value: T) -> Result<(), AsciiError> {
+        let byte = value.try_ascii_ord().map_err(|err| err.at(self.len()))?;
+        self.bytes.push_back(byte);
+        Ok(())
+    }
+    /// Pushes a char, byte or `AsciiChar` onto the front of the `AsciiDeque`.
+    /// # Panics
+    /// * If a char is supplied and is not ASCII/Extended ASCII.
+    #[inline]
+    pub fn push_front<T: CharToAsciiOrd>(&mut self, value: T) {
+        self.bytes.push_front(value.ascii_ord_unchecked());
+    }
+    /// Pushes a char, byte or `AsciiChar` onto the front of the `AsciiDeque`.
+    /// # Errors
+    /// * If a char is supplied and is not ASCII/Extended ASCII.
+    pub fn try_push_front<T: CharToAsciiOrd>(&mut self, value: T) -> Result<(), AsciiError> {
+        let byte = value.try_ascii_ord().map_err(|err| err.at(0))?;
+        self.bytes.push_front(byte);
+        Ok(())
+    }
+    /// Pushes a string onto the end of the `AsciiDeque`.
+    /// # Panics
+    /// * if the string contains any non ASCII/Extended ASCII characters.
+    pub fn push_str(&mut self, string: &str) {
+        self.try_push_str(string)
+            .expect("string contains non ASCII characters");
+    }
+    /// Pushes a string onto the end of the `AsciiDeque`.
+    /// # Errors
+    /// * if the string contains any non ASCII/Extended ASCII characters (the `AsciiDeque` is left unchanged).
+    pub fn try_push_str(&mut self, string: &str) -> Result<(), AsciiError> {
+        let mut bytes = Vec::new();
+        utf8_to_latin1(string, &mut bytes)?;
+        self.bytes.extend(bytes);
+        Ok(())
+    }
+    /// Pushes raw bytes onto the end of the `AsciiDeque`.
+    #[inline]
+    pub fn push_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend(bytes);
+    }
+    /// Pops a byte from the end of the `AsciiDeque`.
+    #[inline]
+    pub fn pop(&mut self) -> Option<u8> {
+        self.bytes.pop_back()
+    }
+    /// Pops a byte from the front of the `AsciiDeque`.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<u8> {
+        self.bytes.pop_front()
+    }
+    /// Pops a char from the end of the `AsciiDeque`.
+    #[inline]
+    pub fn pop_char(&mut self) -> Option<char> {
+        self.pop().map(|byte| byte.to_ascii_char())
+    }
+    /// Pops a char from the front of the `AsciiDeque`.
+    #[inline]
+    pub fn pop_front_char(&mut self) -> Option<char> {
+        self.pop_front().map(|byte| byte.to_ascii_char())
+    }
+    /// Pops an `AsciiChar` from the end of the `AsciiDeque`.
+    #[inline]
+    pub fn pop_ascii_char(&mut self) -> Option<AsciiChar> {
+        self.pop().map(AsciiChar::from)
+    }
+    /// Pops an `AsciiChar` from the front of the `AsciiDeque`.
+    #[inline]
+    pub fn pop_front_ascii_char(&mut self) -> Option<AsciiChar> {
+        self.pop_front().map(AsciiChar::from)
+    }
+    /// Returns an iterator over the bytes, front to back.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, u8> {
+        self.bytes.iter()
+    }
+    /// Returns the contents as two byte slices, front then back (the second is empty when contiguous).
+    #[inline]
+    pub fn as_slices(&self) -> (&[u8], &[u8]) {
+        self.bytes.as_slices()
+    }
+    /// Rearranges the bytes so they are contiguous and borrows them as an `AsciiStr`.
+    /// * only moves bytes if the `AsciiDeque` has wrapped around.
+    #[inline]
+    pub fn make_contiguous(&mut self) -> &mut AsciiStr {
+        AsciiStr::from_bytes_mut(self.bytes.make_contiguous())
+    }
+    /// Fills buf from the front of the `AsciiDeque`, returning the number of bytes read.
+    /// * this consumes the bytes read, without moving the remaining ones.
+    pub fn read(&mut self, buf: &mut [u8]) -> Result<usize, std::io::Error> {
+        self.bytes.read(buf)
+    }
+    /// Writes buf onto the end of the `AsciiDeque`, returning the number of bytes written.
+    #[inline]
+    pub fn write(&mut self, buf: &[u8]) -> Result<usize, std::io::Error> {
+        self.bytes.write(buf)
+    }
+}
+
+/// Reads from the front of the `AsciiDeque`, consuming the bytes read (see `AsciiDeque::read`).
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// use std::io::Read;
+///
+/// let mut deque = AsciiDeque::from(AsciiString::try_from("ABCDE").unwrap());
+/// let mut buf = [0u8; 2];
+/// deque.read_exact(&mut buf).unwrap();
+/// assert_eq!(&buf, b"AB");
+/// let mut rest = String::new();
+/// deque.read_to_string(&mut rest).unwrap();
+/// assert_eq!(rest, "CDE");
+/// assert!(deque.is_empty());
+/// ```
+impl Read for AsciiDeque {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        AsciiDeque::read(self, buf)
+    }
+}
+
+/// Appends to the `AsciiDeque` (see `AsciiDeque::write`).
+impl Write for AsciiDeque {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        AsciiDeque::write(self, buf)
+    }
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Converts without copying.
+impl From<AsciiString> for AsciiDeque {
+    #[inline]
+    fn from(value: AsciiString) -> Self {
+        Self {
+            bytes: value.bytes.into(),
+        }
+    }
+}
+
+/// Reuses the allocation, moving bytes only if the `AsciiDeque` has wrapped around.
+impl From<AsciiDeque> for AsciiString {
+    #[inline]
+    fn from(value: AsciiDeque) -> Self {
+        Self::from(value.bytes)
+    }
+}
+
+impl From<VecDeque<u8>> for AsciiDeque {
+    #[inline]
+    fn from(value: VecDeque<u8>) -> Self {
+        Self { bytes: value }
+    }
+}
+
+impl From<AsciiDeque> for VecDeque<u8> {
+    #[inline]
+    fn from(value: AsciiDeque) -> Self {
+        value.bytes
+    }
+}
+
+impl From<&[u8]> for AsciiDeque {
+    #[inline]
+    fn from(value: &[u8]) -> Self {
+        Self {
+            bytes: value.iter().copied().collect(),
+        }
+    }
+}
+
+impl TryFrom<&str> for AsciiDeque {
+    type Error = AsciiError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        AsciiString::try_from(value).map(Self::from)
+    }
+}
+
+impl Display for AsciiDeque {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (front, back) = self.bytes.as_slices();
+        write_latin1(f, front)?;
+        write_latin1(f, back)
+    }
+}
+
+impl Debug for AsciiDeque {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ascii_error::AsciiErrorKind;
+
+    #[test]
+    fn test_push_pop() {
+        let mut deque = AsciiDeque::with_capacity(4);
+        deque.push('b');
+        deque.push(AsciiChar::LATIN_SMALL_LETTER_C);
+        deque.push_front(b'a');
+        deque.push_str("dé");
+        assert_eq!(deque.len(), 5);
+        assert_eq!(deque.to_string(), "abcdé");
+
+        let error = deque.try_push('€').unwrap_err();
+        assert_eq!(error.kind(), AsciiErrorKind::NonAscii);
+        assert_eq!(error.char_index(), 5);
+        let error = deque.try_push_str("xy€").unwrap_err();
+        assert_eq!(error.char_index(), 2);
+        assert_eq!(deque.try_push_front('€').unwrap_err().char_index(), 0);
+        assert_eq!(deque.len(), 5);
+
+        assert_eq!(
+            deque.pop_front_ascii_char(),
+            Some(AsciiChar::LATIN_SMALL_LETTER_A)
+        );
+        assert_eq!(deque.pop_char(), Some('é'));
+        assert_eq!(deque.pop_front_char(), Some('b'));
+        assert_eq!(
+            deque.pop_ascii_char(),
+            Some(AsciiChar::LATIN_SMALL_LETTER_D)
+        );
+        assert_eq!(deque.pop(), Some(b'c'));
+        assert_eq!(deque.pop_front(), None);
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_wrapped() {
+        // push at both ends so the contents wrap around the ring buffer.
+        let mut deque = AsciiDeque::with_capacity(8);
+        deque.push_str("DEF");
+        deque.push_front('C');
+        deque.push_front('B');
+        deque.push_front('A');
+        assert_eq!(deque.iter().copied().collect::<Vec<_>>(), b"ABCDEF");
+        let (front, back) = deque.as_slices();
+        assert_eq!([front, back].concat(), b"ABCDEF");
+        assert_eq!(format!("{deque}"), "ABCDEF");
+
+        assert_eq!(deque.clone().make_contiguous().find("CD"), Some(2));
+        let astring = AsciiString::from(deque.clone());
+        assert_eq!(astring.as_bytes(), b"ABCDEF");
+        assert_eq!(AsciiDeque::from(astring), deque);
+    }
+
+    #[test]
+    fn test_io() {
+        let mut deque = AsciiDeque::try_from("ABC").unwrap();
+        deque.write_all(b"DEF").unwrap();
+        let mut buf = [0u8; 2];
+        let mut read = Vec::new();
+        loop {
+            let len = Read::read(&mut deque, &mut buf).unwrap();
+            if len == 0 {
+                break;
+            }
+            read.extend_from_slice(&buf[..len]);
+        }
+        assert_eq!(read, b"ABCDEF");
+        assert!(deque.is_empty());
+        assert!(AsciiDeque::try_from("€").is_err());
+        assert_eq!(AsciiDeque::from(&b"xy"[..]).to_string(), "xy");
+    }
+}
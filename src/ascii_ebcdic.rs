@@ -0,0 +1,487 @@
+use crate::ascii_codepage::SingleByteCodec;
+pub use crate::ascii_common::ReadLineResult;
+use crate::ascii_common::{LineEnding, LineScanner, DEFAULT_BUF_SIZE};
+use crate::ascii_consts::{EXT_133, LF};
+use crate::ascii_str::AsciiStr;
+use crate::ascii_string::AsciiString;
+use std::io::{BufRead, BufReader, ErrorKind, Read};
+
+/// EBCDIC newline (NL), the usual line terminator of mainframe text (decodes to NEL, 0x85).
+pub const EBCDIC_NL: u8 = 0x15;
+/// EBCDIC line feed (LF), decodes to `\n`.
+pub const EBCDIC_LF: u8 = 0x25;
+/// EBCDIC carriage return (CR), decodes to `\r`.
+pub const EBCDIC_CR: u8 = 0x0D;
+
+/// An EBCDIC code page, used to convert mainframe data to and from `AsciiString`.
+/// <br>
+/// Each of the code pages contains exactly the 256 Latin-1 characters in a different order,
+/// so conversions never fail, and are done in place (byte for byte).
+/// <br>
+/// The code pages also implement `SingleByteCodec`, to decode EBCDIC bytes straight to Unicode.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// let ebcdic = vec![0xC8, 0x85, 0x93, 0x93, 0x96, 0x5A];
+/// let astring = AsciiString::from_ebcdic(ebcdic, EbcdicCodePage::Cp037);
+/// assert_eq!(astring.to_string(), "Hello!");
+/// assert_eq!(astring.into_ebcdic(EbcdicCodePage::Cp500), [0xC8, 0x85, 0x93, 0x93, 0x96, 0x4F]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EbcdicCodePage {
+    /// CP037, EBCDIC US/Canada.
+    Cp037,
+    /// CP500, EBCDIC International (Belgium, Switzerland).
+    Cp500,
+    /// CP1047, EBCDIC Latin-1/Open Systems, used by z/OS Unix.
+    /// <br>
+    /// Same as CP037 except for `^`, `¬`, `[`, `]`, `Ý` and `¨`.
+    Cp1047,
+}
+
+impl EbcdicCodePage {
+    #[inline(always)]
+    const fn tables(self) -> (&'static [u8; 256], &'static [u8; 256]) {
+        match self {
+            EbcdicCodePage::Cp037 => (&CP037_TO_LATIN1, &LATIN1_TO_CP037),
+            EbcdicCodePage::Cp500 => (&CP500_TO_LATIN1, &LATIN1_TO_CP500),
+            EbcdicCodePage::Cp1047 => (&CP1047_TO_LATIN1, &LATIN1_TO_CP1047),
+        }
+    }
+    /// Converts an EBCDIC byte to the Latin-1 (`AsciiString`) byte of the same character.
+    #[inline(always)]
+    pub const fn to_latin1(self, byte: u8) -> u8 {
+        self.tables().0[byte as usize]
+    }
+    /// Converts a Latin-1 (`AsciiString`) byte to the EBCDIC byte of the same character.
+    #[inline(always)]
+    pub const fn from_latin1(self, byte: u8) -> u8 {
+        self.tables().1[byte as usize]
+    }
+    /// Converts EBCDIC bytes to Latin-1 in place.
+    #[inline]
+    pub fn decode_in_place(self, bytes: &mut [u8]) {
+        let table = self.tables().0;
+        bytes
+            .iter_mut()
+            .for_each(|byte| *byte = table[*byte as usize]);
+    }
+    /// Converts Latin-1 bytes to EBCDIC in place.
+    #[inline]
+    pub fn encode_in_place(self, bytes: &mut [u8]) {
+        let table = self.tables().1;
+        bytes
+            .iter_mut()
+            .for_each(|byte| *byte = table[*byte as usize]);
+    }
+}
+
+impl SingleByteCodec for EbcdicCodePage {
+    #[inline(always)]
+    fn decode(&self, byte: u8) -> char {
+        self.to_latin1(byte) as char
+    }
+    #[inline(always)]
+    fn encode(&self, character: char) -> Option<u8> {
+        u8::try_from(character)
+            .ok()
+            .map(|byte| self.from_latin1(byte))
+    }
+}
+
+impl AsciiStr {
+    /// Copies the `AsciiStr` into a new EBCDIC encoded `Vec<u8>`.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("[A]").unwrap();
+    /// assert_eq!(astring.to_ebcdic(EbcdicCodePage::Cp037), [0xBA, 0xC1, 0xBB]);
+    /// assert_eq!(astring.to_ebcdic(EbcdicCodePage::Cp1047), [0xAD, 0xC1, 0xBD]);
+    /// ```
+    pub fn to_ebcdic(&self, code_page: EbcdicCodePage) -> Vec<u8> {
+        let mut bytes = self.as_bytes().to_vec();
+        code_page.encode_in_place(&mut bytes);
+        bytes
+    }
+}
+
+impl AsciiString {
+    /// Creates an `AsciiString` from EBCDIC encoded bytes.
+    /// * the bytes are converted in place, so a `Vec<u8>` is reused without copying.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::from_ebcdic(vec![0xE2, 0xE3, 0xD6, 0xD7], EbcdicCodePage::Cp500);
+    /// assert_eq!(astring.to_string(), "STOP");
+    /// ```
+    pub fn from_ebcdic<T: Into<Vec<u8>>>(bytes: T, code_page: EbcdicCodePage) -> AsciiString {
+        let mut bytes = bytes.into();
+        code_page.decode_in_place(&mut bytes);
+        AsciiString::from(bytes)
+    }
+    /// Converts the `AsciiString` into EBCDIC encoded bytes.
+    /// * the bytes are converted in place, without copying.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("STOP").unwrap();
+    /// assert_eq!(astring.into_ebcdic(EbcdicCodePage::Cp500), [0xE2, 0xE3, 0xD6, 0xD7]);
+    /// ```
+    pub fn into_ebcdic(self, code_page: EbcdicCodePage) -> Vec<u8> {
+        let mut bytes = self.into_bytes();
+        code_page.encode_in_place(&mut bytes);
+        bytes
+    }
+}
+
+/// A buffered reader which reads EBCDIC data, converting it to `AsciiString`s on the fly.
+/// <br>
+/// Lines are terminated by NL (0x15) or LF (0x25), optionally preceded by CR (0x0D).
+/// <br>
+/// The data is converted as it is read into the internal buffer, so lines are split on the converted
+/// bytes: NL is NEL (0x85), LF is LF and CR is CR, and `set_line_ending` takes ASCII terminators.
+/// # Sample Usage
+/// ```
+/// # use cj_ascii::prelude::*;
+/// # use std::io::Cursor;
+/// // "LINE 1" NL "LINE 2" CR LF "LINE 3" in CP037
+/// let data = [
+///     0xD3, 0xC9, 0xD5, 0xC5, 0x40, 0xF1, 0x15,
+///     0xD3, 0xC9, 0xD5, 0xC5, 0x40, 0xF2, 0x0D, 0x25,
+///     0xD3, 0xC9, 0xD5, 0xC5, 0x40, 0xF3,
+/// ];
+/// let mut reader = EbcdicStreamReader::new(Cursor::new(data), EbcdicCodePage::Cp037);
+///
+/// let mut astring = AsciiString::new();
+/// let mut lines = Vec::new();
+/// while reader.read_line(&mut astring).is_success() {
+///     lines.push(astring.to_string());
+/// }
+/// assert_eq!(lines, ["LINE 1", "LINE 2", "LINE 3"]);
+/// ```
+#[derive(Debug)]
+pub struct EbcdicStreamReader<R> {
+    inner: BufReader<EbcdicDecoder<R>>,
+    scanner: LineScanner,
+}
+
+impl<R: Read> EbcdicStreamReader<R> {
+    /// The default line ending, NL (decoded to NEL) or LF.
+    const LINE_ENDING: LineEnding = LineEnding::custom(&[EXT_133, LF]);
+
+    /// Creates a new EbcdicStreamReader with a default 8KB buffer capacity.
+    pub fn new(inner: R, code_page: EbcdicCodePage) -> Self {
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner, code_page)
+    }
+    /// Creates a new EbcdicStreamReader with the specified buffer capacity.
+    pub fn with_capacity(capacity: usize, inner: R, code_page: EbcdicCodePage) -> Self {
+        let mut scanner = LineScanner::default();
+        scanner.ending = Self::LINE_ENDING;
+        scanner.cr_before_custom = true;
+        Self {
+            inner: BufReader::with_capacity(capacity, EbcdicDecoder { inner, code_page }),
+            scanner,
+        }
+    }
+    /// Returns the number of bytes the internal buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+    /// Returns the EBCDIC code page used to convert the data.
+    pub fn code_page(&self) -> EbcdicCodePage {
+        self.inner.get_ref().code_page
+    }
+    /// Returns the line terminator(s) read_line() splits on.
+    pub fn line_ending(&self) -> LineEnding {
+        self.scanner.ending
+    }
+    /// Sets the line terminator(s) read_line() splits on, as converted (ASCII) bytes.
+    /// * the default is `LineEnding::custom(&[EXT_133, LF])`, EBCDIC NL or LF.
+    /// * a CR right before a `LineEnding::Custom` terminator is part of the terminator.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// # use std::io::Cursor;
+    /// // "A" NL "B" LF in CP500, split on NL only.
+    /// let data = [0xC1, 0x15, 0xC2, 0x25];
+    /// let mut reader = EbcdicStreamReader::new(Cursor::new(data), EbcdicCodePage::Cp500);
+    /// reader.set_line_ending(LineEnding::custom(&[EXT_133]));
+    /// let mut line = AsciiString::new();
+    /// reader.read_line(&mut line);
+    /// assert_eq!(line.to_string(), "A");
+    /// reader.read_line(&mut line);
+    /// assert_eq!(line.to_string(), "B\n");
+    /// ```
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.scanner.ending = line_ending;
+    }
+    /// Returns the maximum line length, if any.
+    pub fn max_line_len(&self) -> Option<usize> {
+        self.scanner.max_line_len
+    }
+    /// Sets the maximum line length, in bytes, not counting the line terminator (the default is None, no limit).
+    /// * read_line() stops at max_line_len bytes and returns `ReadLineResult::TooLong`,
+    ///   with the first max_line_len bytes in the buffer. The next read_line() skips the rest of the line.
+    pub fn set_max_line_len(&mut self, max_line_len: Option<usize>) {
+        self.scanner.max_line_len = max_line_len;
+    }
+    /// Reads the next line of EBCDIC characters, converted to ASCII, into the specified AsciiString.
+    /// * lines end as set by set_line_ending() (NL, LF, CR NL or CR LF by default).
+    /// * the line terminator is discarded.
+    /// * the returned Success(value) is the number of bytes pushed to AsciiString, not the number of bytes read.
+    ///   * returned value of 0 does not mean EOF. It means that the line is empty, but EOF has not been reached.
+    /// * lines longer than max_line_len (if set) return TooLong (see set_max_line_len()).
+    /// * on error, the AsciiString holds the (converted) bytes read before the error.
+    pub fn read_line(&mut self, buf: &mut AsciiString) -> ReadLineResult {
+        self.scanner.start(buf);
+        loop {
+            let available = match self.inner.fill_buf() {
+                Ok(available) => available,
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => return self.scanner.error(err),
+            };
+            let (used, result) = self.scanner.feed(available, buf);
+            self.inner.consume(used);
+            if let Some(result) = result {
+                return result;
+            }
+        }
+    }
+    /// Reads until EOF is reached, converted to ASCII, into the specified AsciiString.
+    pub fn read_to_end(&mut self, buf: &mut AsciiString) -> std::io::Result<usize> {
+        buf.clear();
+        self.inner.read_to_end(buf.as_mut_vec())
+    }
+    /// Reads up to the specified number of bytes, converted to ASCII, into the specified AsciiString.
+    pub fn read_bytes(&mut self, buf: &mut AsciiString, len: usize) -> std::io::Result<usize> {
+        buf.clear();
+        let vec = buf.as_mut_vec();
+        vec.resize(len, 0);
+        let result = self.inner.read(vec);
+        vec.truncate(*result.as_ref().unwrap_or(&0));
+        result
+    }
+}
+
+/// Converts the bytes read from the inner reader from EBCDIC to Latin-1.
+#[derive(Debug)]
+struct EbcdicDecoder<R> {
+    inner: R,
+    code_page: EbcdicCodePage,
+}
+
+impl<R: Read> Read for EbcdicDecoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.code_page.decode_in_place(&mut buf[..read]);
+        Ok(read)
+    }
+}
+
+/// CP037 (EBCDIC US/Canada) byte to Latin-1 byte.
+static CP037_TO_LATIN1: [u8; 256] = [
+    0x00, 0x01, 0x02, 0x03, 0x9C, 0x09, 0x86, 0x7F, 0x97, 0x8D, 0x8E, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+    0x10, 0x11, 0x12, 0x13, 0x9D, 0x85, 0x08, 0x87, 0x18, 0x19, 0x92, 0x8F, 0x1C, 0x1D, 0x1E, 0x1F,
+    0x80, 0x81, 0x82, 0x83, 0x84, 0x0A, 0x17, 0x1B, 0x88, 0x89, 0x8A, 0x8B, 0x8C, 0x05, 0x06, 0x07,
+    0x90, 0x91, 0x16, 0x93, 0x94, 0x95, 0x96, 0x04, 0x98, 0x99, 0x9A, 0x9B, 0x14, 0x15, 0x9E, 0x1A,
+    0x20, 0xA0, 0xE2, 0xE4, 0xE0, 0xE1, 0xE3, 0xE5, 0xE7, 0xF1, 0xA2, 0x2E, 0x3C, 0x28, 0x2B, 0x7C,
+    0x26, 0xE9, 0xEA, 0xEB, 0xE8, 0xED, 0xEE, 0xEF, 0xEC, 0xDF, 0x21, 0x24, 0x2A, 0x29, 0x3B, 0xAC,
+    0x2D, 0x2F, 0xC2, 0xC4, 0xC0, 0xC1, 0xC3, 0xC5, 0xC7, 0xD1, 0xA6, 0x2C, 0x25, 0x5F, 0x3E, 0x3F,
+    0xF8, 0xC9, 0xCA, 0xCB, 0xC8, 0xCD, 0xCE, 0xCF, 0xCC, 0x60, 0x3A, 0x23, 0x40, 0x27, 0x3D, 0x22,
+    0xD8, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0xAB, 0xBB, 0xF0, 0xFD, 0xFE, 0xB1,
+    0xB0, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0xAA, 0xBA, 0xE6, 0xB8, 0xC6, 0xA4,
+    0xB5, 0x7E, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0xA1, 0xBF, 0xD0, 0xDD, 0xDE, 0xAE,
+    0x5E, 0xA3, 0xA5, 0xB7, 0xA9, 0xA7, 0xB6, 0xBC, 0xBD, 0xBE, 0x5B, 0x5D, 0xAF, 0xA8, 0xB4, 0xD7,
+    0x7B, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0xAD, 0xF4, 0xF6, 0xF2, 0xF3, 0xF5,
+    0x7D, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0xB9, 0xFB, 0xFC, 0xF9, 0xFA, 0xFF,
+    0x5C, 0xF7, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0xB2, 0xD4, 0xD6, 0xD2, 0xD3, 0xD5,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0xB3, 0xDB, 0xDC, 0xD9, 0xDA, 0x9F,
+];
+
+/// CP500 (EBCDIC International) byte to Latin-1 byte.
+static CP500_TO_LATIN1: [u8; 256] = [
+    0x00, 0x01, 0x02, 0x03, 0x9C, 0x09, 0x86, 0x7F, 0x97, 0x8D, 0x8E, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+    0x10, 0x11, 0x12, 0x13, 0x9D, 0x85, 0x08, 0x87, 0x18, 0x19, 0x92, 0x8F, 0x1C, 0x1D, 0x1E, 0x1F,
+    0x80, 0x81, 0x82, 0x83, 0x84, 0x0A, 0x17, 0x1B, 0x88, 0x89, 0x8A, 0x8B, 0x8C, 0x05, 0x06, 0x07,
+    0x90, 0x91, 0x16, 0x93, 0x94, 0x95, 0x96, 0x04, 0x98, 0x99, 0x9A, 0x9B, 0x14, 0x15, 0x9E, 0x1A,
+    0x20, 0xA0, 0xE2, 0xE4, 0xE0, 0xE1, 0xE3, 0xE5, 0xE7, 0xF1, 0x5B, 0x2E, 0x3C, 0x28, 0x2B, 0x21,
+    0x26, 0xE9, 0xEA, 0xEB, 0xE8, 0xED, 0xEE, 0xEF, 0xEC, 0xDF, 0x5D, 0x24, 0x2A, 0x29, 0x3B, 0x5E,
+    0x2D, 0x2F, 0xC2, 0xC4, 0xC0, 0xC1, 0xC3, 0xC5, 0xC7, 0xD1, 0xA6, 0x2C, 0x25, 0x5F, 0x3E, 0x3F,
+    0xF8, 0xC9, 0xCA, 0xCB, 0xC8, 0xCD, 0xCE, 0xCF, 0xCC, 0x60, 0x3A, 0x23, 0x40, 0x27, 0x3D, 0x22,
+    0xD8, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0xAB, 0xBB, 0xF0, 0xFD, 0xFE, 0xB1,
+    0xB0, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0xAA, 0xBA, 0xE6, 0xB8, 0xC6, 0xA4,
+    0xB5, 0x7E, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0xA1, 0xBF, 0xD0, 0xDD, 0xDE, 0xAE,
+    0xA2, 0xA3, 0xA5, 0xB7, 0xA9, 0xA7, 0xB6, 0xBC, 0xBD, 0xBE, 0xAC, 0x7C, 0xAF, 0xA8, 0xB4, 0xD7,
+    0x7B, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0xAD, 0xF4, 0xF6, 0xF2, 0xF3, 0xF5,
+    0x7D, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0xB9, 0xFB, 0xFC, 0xF9, 0xFA, 0xFF,
+    0x5C, 0xF7, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0xB2, 0xD4, 0xD6, 0xD2, 0xD3, 0xD5,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0xB3, 0xDB, 0xDC, 0xD9, 0xDA, 0x9F,
+];
+
+/// CP1047 (EBCDIC Latin-1/Open Systems) byte to Latin-1 byte.
+static CP1047_TO_LATIN1: [u8; 256] = [
+    0x00, 0x01, 0x02, 0x03, 0x9C, 0x09, 0x86, 0x7F, 0x97, 0x8D, 0x8E, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+    0x10, 0x11, 0x12, 0x13, 0x9D, 0x85, 0x08, 0x87, 0x18, 0x19, 0x92, 0x8F, 0x1C, 0x1D, 0x1E, 0x1F,
+    0x80, 0x81, 0x82, 0x83, 0x84, 0x0A, 0x17, 0x1B, 0x88, 0x89, 0x8A, 0x8B, 0x8C, 0x05, 0x06, 0x07,
+    0x90, 0x91, 0x16, 0x93, 0x94, 0x95, 0x96, 0x04, 0x98, 0x99, 0x9A, 0x9B, 0x14, 0x15, 0x9E, 0x1A,
+    0x20, 0xA0, 0xE2, 0xE4, 0xE0, 0xE1, 0xE3, 0xE5, 0xE7, 0xF1, 0xA2, 0x2E, 0x3C, 0x28, 0x2B, 0x7C,
+    0x26, 0xE9, 0xEA, 0xEB, 0xE8, 0xED, 0xEE, 0xEF, 0xEC, 0xDF, 0x21, 0x24, 0x2A, 0x29, 0x3B, 0x5E,
+    0x2D, 0x2F, 0xC2, 0xC4, 0xC0, 0xC1, 0xC3, 0xC5, 0xC7, 0xD1, 0xA6, 0x2C, 0x25, 0x5F, 0x3E, 0x3F,
+    0xF8, 0xC9, 0xCA, 0xCB, 0xC8, 0xCD, 0xCE, 0xCF, 0xCC, 0x60, 0x3A, 0x23, 0x40, 0x27, 0x3D, 0x22,
+    0xD8, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0xAB, 0xBB, 0xF0, 0xFD, 0xFE, 0xB1,
+    0xB0, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0xAA, 0xBA, 0xE6, 0xB8, 0xC6, 0xA4,
+    0xB5, 0x7E, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0xA1, 0xBF, 0xD0, 0x5B, 0xDE, 0xAE,
+    0xAC, 0xA3, 0xA5, 0xB7, 0xA9, 0xA7, 0xB6, 0xBC, 0xBD, 0xBE, 0xDD, 0xA8, 0xAF, 0x5D, 0xB4, 0xD7,
+    0x7B, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0xAD, 0xF4, 0xF6, 0xF2, 0xF3, 0xF5,
+    0x7D, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0xB9, 0xFB, 0xFC, 0xF9, 0xFA, 0xFF,
+    0x5C, 0xF7, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0xB2, 0xD4, 0xD6, 0xD2, 0xD3, 0xD5,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0xB3, 0xDB, 0xDC, 0xD9, 0xDA, 0x9F,
+];
+
+const fn invert(table: &[u8; 256]) -> [u8; 256] {
+    let mut inverse = [0u8; 256];
+    let mut inx = 0;
+    while inx < 256 {
+        inverse[table[inx] as usize] = inx as u8;
+        inx += 1;
+    }
+    inverse
+}
+
+static LATIN1_TO_CP037: [u8; 256] = invert(&CP037_TO_LATIN1);
+static LATIN1_TO_CP500: [u8; 256] = invert(&CP500_TO_LATIN1);
+static LATIN1_TO_CP1047: [u8; 256] = invert(&CP1047_TO_LATIN1);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Cursor;
+
+    const CODE_PAGES: [EbcdicCodePage; 3] = [
+        EbcdicCodePage::Cp037,
+        EbcdicCodePage::Cp500,
+        EbcdicCodePage::Cp1047,
+    ];
+
+    #[test]
+    fn test_round_trip() {
+        for code_page in CODE_PAGES {
+            for byte in 0..=255u8 {
+                assert_eq!(code_page.from_latin1(code_page.to_latin1(byte)), byte);
+                assert_eq!(code_page.to_latin1(code_page.from_latin1(byte)), byte);
+            }
+            let all: Vec<u8> = (0..=255).collect();
+            let astring = AsciiString::from_ebcdic(all.clone(), code_page);
+            assert_eq!(astring.to_ebcdic(code_page), all);
+            assert_eq!(astring.into_ebcdic(code_page), all);
+        }
+    }
+
+    #[test]
+    fn test_known_characters() {
+        let text = "Hello, World! [0-9] ^¬|¢";
+        let cp037 = [
+            0xC8, 0x85, 0x93, 0x93, 0x96, 0x6B, 0x40, 0xE6, 0x96, 0x99, 0x93, 0x84, 0x5A, 0x40,
+            0xBA, 0xF0, 0x60, 0xF9, 0xBB, 0x40, 0xB0, 0x5F, 0x4F, 0x4A,
+        ];
+        let astring = AsciiString::try_from(text).unwrap();
+        assert_eq!(astring.to_ebcdic(EbcdicCodePage::Cp037), cp037);
+        let cp1047 = astring.to_ebcdic(EbcdicCodePage::Cp1047);
+        assert_eq!(cp1047[14..19], [0xAD, 0xF0, 0x60, 0xF9, 0xBD]);
+        assert_eq!(cp1047[20..22], [0x5F, 0xB0]);
+        let cp500 = astring.to_ebcdic(EbcdicCodePage::Cp500);
+        assert_eq!(cp500[12], 0x4F);
+        assert_eq!(cp500[14], 0x4A);
+        // decoding straight to Unicode.
+        assert_eq!(
+            AsciiStr::from_bytes(&cp037).to_string_with(EbcdicCodePage::Cp037),
+            text
+        );
+    }
+
+    #[test]
+    fn test_ebcdic_stream_reader() {
+        let cp = EbcdicCodePage::Cp037;
+        let mut data = AsciiString::try_from("line1").unwrap().into_ebcdic(cp);
+        data.push(EBCDIC_NL);
+        data.extend(AsciiString::try_from("line2").unwrap().into_ebcdic(cp));
+        data.extend([EBCDIC_CR, EBCDIC_NL, EBCDIC_NL, EBCDIC_CR]);
+        data.extend(AsciiString::try_from("line4").unwrap().into_ebcdic(cp));
+        data.push(EBCDIC_LF);
+
+        // a tiny buffer, so lines span several reads.
+        let mut reader = EbcdicStreamReader::with_capacity(2, Cursor::new(data), cp);
+        let mut buf = AsciiString::new();
+        assert_eq!(reader.read_line(&mut buf).unwrap(), 5);
+        assert_eq!(buf.to_string(), "line1");
+        reader.read_line(&mut buf).unwrap();
+        assert_eq!(buf.to_string(), "line2");
+        assert_eq!(reader.read_line(&mut buf).unwrap(), 0);
+        reader.read_line(&mut buf).unwrap();
+        // CR is only a terminator when it is followed by NL or LF.
+        assert_eq!(buf.to_string(), "\rline4");
+        assert!(reader.read_line(&mut buf).is_eof());
+    }
+
+    #[test]
+    fn test_ebcdic_stream_reader_settings() {
+        let cp = EbcdicCodePage::Cp1047;
+        let mut data = AsciiString::try_from("too long").unwrap().into_ebcdic(cp);
+        data.extend([EBCDIC_CR, EBCDIC_NL]);
+        data.extend(AsciiString::try_from("ok").unwrap().into_ebcdic(cp));
+        data.extend([EBCDIC_CR, EBCDIC_NL]);
+        data.extend(AsciiString::try_from("a\rb").unwrap().into_ebcdic(cp));
+
+        let mut reader = EbcdicStreamReader::with_capacity(3, Cursor::new(data), cp);
+        reader.set_max_line_len(Some(3));
+        let mut buf = AsciiString::new();
+        assert!(matches!(
+            reader.read_line(&mut buf),
+            ReadLineResult::TooLong(3)
+        ));
+        assert_eq!(buf.to_string(), "too");
+        assert_eq!(reader.read_line(&mut buf).unwrap(), 2);
+        assert_eq!(buf.to_string(), "ok");
+        reader.set_line_ending(LineEnding::Cr);
+        reader.read_line(&mut buf).unwrap();
+        assert_eq!(buf.to_string(), "a");
+        reader.read_line(&mut buf).unwrap();
+        assert_eq!(buf.to_string(), "b");
+    }
+
+    #[test]
+    fn test_ebcdic_stream_reader_error() {
+        // fails after the first read.
+        struct Failing(bool);
+        impl Read for Failing {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                if std::mem::replace(&mut self.0, true) {
+                    return Err(std::io::Error::other("broken"));
+                }
+                // "ok" NL "x" in CP037
+                buf[..4].copy_from_slice(&[0x96, 0x92, EBCDIC_NL, 0xA7]);
+                Ok(4)
+            }
+        }
+        let mut reader = EbcdicStreamReader::new(Failing(false), EbcdicCodePage::Cp037);
+        let mut buf = AsciiString::new();
+        assert_eq!(reader.read_line(&mut buf).unwrap(), 2);
+        assert_eq!(buf.to_string(), "ok");
+        assert!(reader.read_line(&mut buf).is_error());
+        // the part of the line read before the error is converted.
+        assert_eq!(buf.to_string(), "x");
+    }
+
+    #[test]
+    fn test_ebcdic_stream_reader_to_end() {
+        let cp = EbcdicCodePage::Cp500;
+        let data = AsciiString::try_from("abc[]").unwrap().into_ebcdic(cp);
+        let mut reader = EbcdicStreamReader::new(Cursor::new(data), cp);
+        let mut buf = AsciiString::new();
+        assert_eq!(reader.read_bytes(&mut buf, 2).unwrap(), 2);
+        assert_eq!(buf.to_string(), "ab");
+        assert_eq!(reader.read_to_end(&mut buf).unwrap(), 3);
+        assert_eq!(buf.to_string(), "c[]");
+    }
+}
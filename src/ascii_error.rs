@@ -0,0 +1,173 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// The kind of error that occurred while converting to ASCII.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AsciiErrorKind {
+    /// The character is not ASCII/Extended ASCII (it is greater than U+00FF).
+    NonAscii,
+    /// The character has no byte value in the code page being used (see `CodePage`).
+    Unmappable,
+    /// The character is Extended ASCII (0x80..=0xFF), where only 7-bit ASCII is allowed (see `Ascii7String`).
+    Extended,
+    /// The character is not printable, where only printable characters are allowed (see `PrintableAsciiString`).
+    NonPrintable,
+}
+
+impl Display for AsciiErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AsciiErrorKind::NonAscii => write!(f, "Non-ASCII character"),
+            AsciiErrorKind::Unmappable => write!(f, "Unmappable character"),
+            AsciiErrorKind::Extended => write!(f, "Extended ASCII character"),
+            AsciiErrorKind::NonPrintable => write!(f, "Non-printable character"),
+        }
+    }
+}
+
+/// The error returned by fallible ASCII conversions, such as `AsciiString::try_from` and `AsciiString::try_push`.
+/// <br>
+/// Carries the offending character along with its position in the source.
+/// # Examples
+/// ```
+/// use cj_ascii::prelude::*;
+///
+/// let error = AsciiString::try_from("aé€b").unwrap_err();
+/// assert_eq!(error.kind(), AsciiErrorKind::NonAscii);
+/// assert_eq!(error.character(), '€');
+/// assert_eq!(error.char_index(), 2);
+/// assert_eq!(error.byte_index(), 3);
+///
+/// // can be converted to std::io::Error, so ? works in stream code.
+/// let io_error: std::io::Error = error.into();
+/// assert_eq!(io_error.kind(), std::io::ErrorKind::InvalidData);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AsciiError {
+    kind: AsciiErrorKind,
+    character: char,
+    char_index: usize,
+    byte_index: usize,
+}
+
+impl AsciiError {
+    /// Creates a new `AsciiError`.
+    pub const fn new(
+        kind: AsciiErrorKind,
+        character: char,
+        char_index: usize,
+        byte_index: usize,
+    ) -> Self {
+        Self {
+            kind,
+            character,
+            char_index,
+            byte_index,
+        }
+    }
+    /// Creates a new `AsciiError` of kind `NonAscii`.
+    #[inline]
+    pub const fn non_ascii(character: char, char_index: usize, byte_index: usize) -> Self {
+        Self::new(AsciiErrorKind::NonAscii, character, char_index, byte_index)
+    }
+    /// Creates a new `AsciiError` of kind `Unmappable`.
+    #[inline]
+    pub const fn unmappable(character: char, char_index: usize, byte_index: usize) -> Self {
+        Self::new(
+            AsciiErrorKind::Unmappable,
+            character,
+            char_index,
+            byte_index,
+        )
+    }
+    /// Creates a new `AsciiError` of kind `Extended`.
+    #[inline]
+    pub const fn extended(character: char, char_index: usize, byte_index: usize) -> Self {
+        Self::new(AsciiErrorKind::Extended, character, char_index, byte_index)
+    }
+    /// Creates a new `AsciiError` of kind `NonPrintable`.
+    #[inline]
+    pub const fn non_printable(character: char, char_index: usize, byte_index: usize) -> Self {
+        Self::new(
+            AsciiErrorKind::NonPrintable,
+            character,
+            char_index,
+            byte_index,
+        )
+    }
+    /// Returns the kind of error.
+    #[inline]
+    pub const fn kind(&self) -> AsciiErrorKind {
+        self.kind
+    }
+    /// Returns the character that caused the error.
+    #[inline]
+    pub const fn character(&self) -> char {
+        self.character
+    }
+    /// Returns the index of the offending character, counted in chars.
+    /// <br>
+    /// For single character APIs (such as `AsciiString::try_push` and `try_insert`), this is the index
+    /// the character would have had in the string.
+    #[inline]
+    pub const fn char_index(&self) -> usize {
+        self.char_index
+    }
+    /// Returns the index of the offending character, counted in (UTF-8) bytes.
+    /// <br>
+    /// For single character APIs, this is the same as char_index (one byte per character in the string).
+    #[inline]
+    pub const fn byte_index(&self) -> usize {
+        self.byte_index
+    }
+    /// Sets both indexes to the position in the string where a single character failed to go.
+    #[inline]
+    pub(crate) const fn at(mut self, index: usize) -> Self {
+        self.char_index = index;
+        self.byte_index = index;
+        self
+    }
+}
+
+impl Display for AsciiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            r#"{} "{}" found at index {} (byte index {})"#,
+            self.kind, self.character, self.char_index, self.byte_index
+        )
+    }
+}
+
+impl Error for AsciiError {}
+
+impl From<AsciiError> for std::io::Error {
+    fn from(value: AsciiError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = AsciiError::non_ascii('€', 3, 4);
+        assert_eq!(
+            error.to_string(),
+            r#"Non-ASCII character "€" found at index 3 (byte index 4)"#
+        );
+    }
+
+    #[test]
+    fn test_into_io_error() {
+        let error = AsciiError::non_ascii('€', 0, 0);
+        let io_error = std::io::Error::from(error.clone());
+        assert_eq!(io_error.kind(), std::io::ErrorKind::InvalidData);
+        let inner = io_error.into_inner().unwrap();
+        assert_eq!(inner.downcast_ref::<AsciiError>(), Some(&error));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::ascii_consts::*;
 use crate::ascii_translators::*;
-use std::collections::vec_deque::Iter;
+use std::slice::Iter;
 
 /// Enum representing the group an ASCII char belongs to.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -0,0 +1,162 @@
+use crate::ascii_consts::SPACE;
+use crate::ascii_string::AsciiString;
+use crate::ascii_traits::CharToAsciiOrd;
+use std::fmt;
+use std::io::Write;
+
+/// How `AsciiString::push_str_with_policy` and `AsciiString::from_str_with_policy` handle characters
+/// that are not ASCII/Extended ASCII (above U+00FF).
+/// <br>
+/// The default is `Replace(SPACE)`, the same as `push_str_lossy`.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// let text = "€5 ☺";
+/// let policies = [
+///     (LossyPolicy::Replace(b'?'), "?5 ?"),
+///     (LossyPolicy::Skip, "5 "),
+///     (LossyPolicy::EscapeUnicode, "\\u{20AC}5 \\u{263A}"),
+///     (LossyPolicy::EscapeHtml, "&#8364;5 &#9786;"),
+/// ];
+/// for (policy, expected) in policies {
+///     let (astring, substituted) = AsciiString::from_str_with_policy(text, policy);
+///     assert_eq!(astring.to_string(), expected);
+///     assert_eq!(substituted, 2);
+/// }
+///
+/// let euro = |c: char, out: &mut AsciiString| {
+///     if c == '€' {
+///         out.push_str_lossy("EUR");
+///     }
+/// };
+/// let (astring, _) = AsciiString::from_str_with_policy(text, LossyPolicy::Custom(&euro));
+/// assert_eq!(astring.to_string(), "EUR5 ");
+/// ```
+#[derive(Clone, Copy)]
+pub enum LossyPolicy<'a> {
+    /// Replaces the character with the byte, such as `?` or `SUB` (0x1A).
+    Replace(u8),
+    /// Drops the character.
+    Skip,
+    /// Escapes the character as `\u{XXXX}` (hex code point, as in Rust source).
+    EscapeUnicode,
+    /// Escapes the character as `&#NNNN;` (decimal code point, as an HTML/XML character reference).
+    EscapeHtml,
+    /// Calls the closure, which pushes the substitution (if any) onto the `AsciiString`.
+    Custom(&'a dyn Fn(char, &mut AsciiString)),
+}
+
+impl Default for LossyPolicy<'_> {
+    fn default() -> Self {
+        LossyPolicy::Replace(SPACE)
+    }
+}
+
+impl fmt::Debug for LossyPolicy<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LossyPolicy::Replace(byte) => f.debug_tuple("Replace").field(byte).finish(),
+            LossyPolicy::Skip => f.write_str("Skip"),
+            LossyPolicy::EscapeUnicode => f.write_str("EscapeUnicode"),
+            LossyPolicy::EscapeHtml => f.write_str("EscapeHtml"),
+            LossyPolicy::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl LossyPolicy<'_> {
+    #[inline]
+    fn substitute(&self, character: char, astring: &mut AsciiString) {
+        // writing to a Vec never fails.
+        match self {
+            LossyPolicy::Replace(byte) => astring.bytes.push(*byte),
+            LossyPolicy::Skip => {}
+            LossyPolicy::EscapeUnicode => {
+                write!(astring.bytes, "\\u{{{:04X}}}", character as u32).unwrap()
+            }
+            LossyPolicy::EscapeHtml => write!(astring.bytes, "&#{};", character as u32).unwrap(),
+            LossyPolicy::Custom(substitute) => substitute(character, astring),
+        }
+    }
+}
+
+impl AsciiString {
+    /// Pushes a string onto the end of the `AsciiString`, handling non ASCII/Extended ASCII characters
+    /// according to the policy (see `LossyPolicy`).
+    /// <br>
+    /// Returns the number of characters that were substituted.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::new();
+    /// assert_eq!(astring.push_str_with_policy("ABCD", LossyPolicy::Replace(SUB)), 0);
+    /// assert_eq!(astring.push_str_with_policy("€é", LossyPolicy::Replace(SUB)), 1);
+    /// assert_eq!(astring.as_bytes(), [b'A', b'B', b'C', b'D', SUB, 0xE9]);
+    /// ```
+    pub fn push_str_with_policy(&mut self, string: &str, policy: LossyPolicy<'_>) -> usize {
+        self.bytes.reserve(string.len());
+        let mut substituted = 0;
+        for character in string.chars() {
+            match character.ascii_ord() {
+                Some(byte) => self.bytes.push(byte),
+                None => {
+                    substituted += 1;
+                    policy.substitute(character, self);
+                }
+            }
+        }
+        substituted
+    }
+    /// Creates an `AsciiString` from a string, handling non ASCII/Extended ASCII characters
+    /// according to the policy (see `LossyPolicy`).
+    /// <br>
+    /// Returns the `AsciiString` and the number of characters that were substituted.
+    pub fn from_str_with_policy(string: &str, policy: LossyPolicy<'_>) -> (AsciiString, usize) {
+        let mut astring = AsciiString::with_capacity(string.len());
+        let substituted = astring.push_str_with_policy(string, policy);
+        (astring, substituted)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_policies() {
+        let text = "a😀b€";
+        let mut astring = AsciiString::new();
+        assert_eq!(
+            astring.push_str_with_policy(text, LossyPolicy::default()),
+            2
+        );
+        assert_eq!(astring.to_string(), "a b ");
+        let (astring, count) = AsciiString::from_str_with_policy(text, LossyPolicy::EscapeUnicode);
+        assert_eq!(astring.to_string(), "a\\u{1F600}b\\u{20AC}");
+        assert_eq!(count, 2);
+        let (astring, _) = AsciiString::from_str_with_policy(text, LossyPolicy::EscapeHtml);
+        assert_eq!(astring.to_string(), "a&#128512;b&#8364;");
+        let (astring, count) = AsciiString::from_str_with_policy("ÿé", LossyPolicy::Skip);
+        assert_eq!(astring.as_bytes(), [0xFF, 0xE9]);
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_custom() {
+        let seen = Cell::new(0);
+        let policy = |c: char, out: &mut AsciiString| {
+            seen.set(seen.get() + 1);
+            out.push_str_transliterate(
+                c.encode_utf8(&mut [0; 4]),
+                crate::ascii_transliterate::Transliteration::default(),
+            );
+        };
+        let (astring, count) =
+            AsciiString::from_str_with_policy("“x” — œ", LossyPolicy::Custom(&policy));
+        assert_eq!(astring.to_string(), "\"x\" -- oe");
+        assert_eq!(count, 4);
+        assert_eq!(seen.get(), 4);
+        assert_eq!(format!("{:?}", LossyPolicy::Custom(&policy)), "Custom(..)");
+    }
+}
@@ -0,0 +1,518 @@
+use crate::ascii_char::AsciiChar;
+use crate::ascii_str::AsciiStr;
+use crate::ascii_string::AsciiString;
+use crate::ascii_translators::char_to_ascii_ord;
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// A pattern that can be searched for in an `AsciiStr` (see `AsciiStr::find`).
+/// <br>
+/// Implemented for:
+/// * `u8`, `char` and `AsciiChar` - a single character.
+/// * `&str`, `&String`, `&AsciiStr`, `&AsciiString`, `&[u8]` and `&[u8; N]` - a sequence of characters.
+/// * `FnMut(u8) -> bool` - a byte predicate.
+///
+/// chars and strings that are not ASCII/Extended ASCII never match (searching never panics).
+pub trait AsciiPattern {
+    /// The searcher used to find this pattern.
+    type Searcher: AsciiSearcher;
+    /// Converts the pattern into its searcher.
+    fn into_searcher(self) -> Self::Searcher;
+}
+
+/// Finds the matches of an `AsciiPattern` in a haystack of bytes.
+/// <br>
+/// All positions are absolute byte offsets into the haystack.
+pub trait AsciiSearcher {
+    /// Returns the range of the first match that starts at or after `start`.
+    fn find_from(&mut self, haystack: &[u8], start: usize) -> Option<Range<usize>>;
+    /// Returns the range of the last match that ends at or before `end`.
+    fn rfind_to(&mut self, haystack: &[u8], end: usize) -> Option<Range<usize>>;
+    /// Returns the length of the match at the start of the haystack, if there is one.
+    fn prefix_len(&mut self, haystack: &[u8]) -> Option<usize>;
+    /// Returns the length of the match at the end of the haystack, if there is one.
+    fn suffix_len(&mut self, haystack: &[u8]) -> Option<usize>;
+}
+
+#[derive(Debug, Clone)]
+enum Needle<'a> {
+    Never,
+    Byte(u8),
+    Bytes(Cow<'a, [u8]>),
+}
+
+/// Searcher for single characters and character sequences.
+/// * single bytes are found with a word-at-a-time (memchr style) scan.
+/// * longer sequences use Boyer-Moore-Horspool, with the skip tables built on first use.
+#[derive(Debug, Clone)]
+pub struct BytesSearcher<'a> {
+    needle: Needle<'a>,
+    forward: Option<Box<[usize; 256]>>,
+    backward: Option<Box<[usize; 256]>>,
+}
+
+impl<'a> BytesSearcher<'a> {
+    fn new(needle: Needle<'a>) -> Self {
+        let needle = match needle {
+            Needle::Bytes(bytes) if bytes.len() == 1 => Needle::Byte(bytes[0]),
+            needle => needle,
+        };
+        Self {
+            needle,
+            forward: None,
+            backward: None,
+        }
+    }
+    /// Creates a searcher for a single byte.
+    #[inline]
+    pub fn byte(byte: u8) -> Self {
+        Self::new(Needle::Byte(byte))
+    }
+    /// Creates a searcher for a sequence of bytes.
+    #[inline]
+    pub fn bytes(bytes: &'a [u8]) -> Self {
+        Self::new(Needle::Bytes(Cow::Borrowed(bytes)))
+    }
+    /// Creates a searcher for a char, which never matches if the char is not ASCII/Extended ASCII.
+    #[inline]
+    pub fn char(character: char) -> Self {
+        match char_to_ascii_ord(character) {
+            Some(byte) => Self::byte(byte),
+            None => Self::new(Needle::Never),
+        }
+    }
+    /// Creates a searcher for a str, which never matches if the str is not ASCII/Extended ASCII.
+    pub fn str(string: &'a str) -> Self {
+        if string.is_ascii() {
+            return Self::bytes(string.as_bytes());
+        }
+        let mut bytes = Vec::with_capacity(string.len());
+        for character in string.chars() {
+            match char_to_ascii_ord(character) {
+                Some(byte) => bytes.push(byte),
+                None => return Self::new(Needle::Never),
+            }
+        }
+        Self::new(Needle::Bytes(Cow::Owned(bytes)))
+    }
+
+    fn forward_table<'t>(
+        needle: &[u8],
+        table: &'t mut Option<Box<[usize; 256]>>,
+    ) -> &'t [usize; 256] {
+        table.get_or_insert_with(|| {
+            let len = needle.len();
+            let mut skip = Box::new([len; 256]);
+            for (inx, byte) in needle[..len - 1].iter().enumerate() {
+                skip[*byte as usize] = len - 1 - inx;
+            }
+            skip
+        })
+    }
+
+    fn backward_table<'t>(
+        needle: &[u8],
+        table: &'t mut Option<Box<[usize; 256]>>,
+    ) -> &'t [usize; 256] {
+        table.get_or_insert_with(|| {
+            let len = needle.len();
+            let mut skip = Box::new([len; 256]);
+            for inx in (1..len).rev() {
+                skip[needle[inx] as usize] = inx;
+            }
+            skip
+        })
+    }
+}
+
+impl AsciiSearcher for BytesSearcher<'_> {
+    fn find_from(&mut self, haystack: &[u8], start: usize) -> Option<Range<usize>> {
+        if start > haystack.len() {
+            return None;
+        }
+        match &self.needle {
+            Needle::Never => None,
+            Needle::Byte(byte) => {
+                memchr(*byte, &haystack[start..]).map(|inx| start + inx..start + inx + 1)
+            }
+            Needle::Bytes(needle) => {
+                let len = needle.len();
+                if len == 0 {
+                    return Some(start..start);
+                }
+                let skip = Self::forward_table(needle, &mut self.forward);
+                let last = needle[len - 1];
+                let mut pos = start;
+                while pos + len <= haystack.len() {
+                    let tail = haystack[pos + len - 1];
+                    if tail == last && haystack[pos..pos + len - 1] == needle[..len - 1] {
+                        return Some(pos..pos + len);
+                    }
+                    pos += skip[tail as usize];
+                }
+                None
+            }
+        }
+    }
+
+    fn rfind_to(&mut self, haystack: &[u8], end: usize) -> Option<Range<usize>> {
+        if end > haystack.len() {
+            return None;
+        }
+        match &self.needle {
+            Needle::Never => None,
+            Needle::Byte(byte) => memrchr(*byte, &haystack[..end]).map(|inx| inx..inx + 1),
+            Needle::Bytes(needle) => {
+                let len = needle.len();
+                if len == 0 {
+                    return Some(end..end);
+                }
+                if end < len {
+                    return None;
+                }
+                let skip = Self::backward_table(needle, &mut self.backward);
+                let first = needle[0];
+                let mut pos = end - len;
+                loop {
+                    let head = haystack[pos];
+                    if head == first && haystack[pos + 1..pos + len] == needle[1..] {
+                        return Some(pos..pos + len);
+                    }
+                    let shift = skip[head as usize];
+                    if pos < shift {
+                        return None;
+                    }
+                    pos -= shift;
+                }
+            }
+        }
+    }
+
+    fn prefix_len(&mut self, haystack: &[u8]) -> Option<usize> {
+        match &self.needle {
+            Needle::Never => None,
+            Needle::Byte(byte) => (haystack.first() == Some(byte)).then_some(1),
+            Needle::Bytes(needle) => haystack.starts_with(needle).then_some(needle.len()),
+        }
+    }
+
+    fn suffix_len(&mut self, haystack: &[u8]) -> Option<usize> {
+        match &self.needle {
+            Needle::Never => None,
+            Needle::Byte(byte) => (haystack.last() == Some(byte)).then_some(1),
+            Needle::Bytes(needle) => haystack.ends_with(needle).then_some(needle.len()),
+        }
+    }
+}
+
+/// Searcher for byte predicates (closures).
+#[derive(Debug, Clone)]
+pub struct PredicateSearcher<F> {
+    predicate: F,
+}
+
+impl<F: FnMut(u8) -> bool> AsciiSearcher for PredicateSearcher<F> {
+    fn find_from(&mut self, haystack: &[u8], start: usize) -> Option<Range<usize>> {
+        let predicate = &mut self.predicate;
+        haystack
+            .get(start..)?
+            .iter()
+            .position(|byte| predicate(*byte))
+            .map(|inx| start + inx..start + inx + 1)
+    }
+
+    fn rfind_to(&mut self, haystack: &[u8], end: usize) -> Option<Range<usize>> {
+        let predicate = &mut self.predicate;
+        haystack
+            .get(..end)?
+            .iter()
+            .rposition(|byte| predicate(*byte))
+            .map(|inx| inx..inx + 1)
+    }
+
+    fn prefix_len(&mut self, haystack: &[u8]) -> Option<usize> {
+        let byte = *haystack.first()?;
+        (self.predicate)(byte).then_some(1)
+    }
+
+    fn suffix_len(&mut self, haystack: &[u8]) -> Option<usize> {
+        let byte = *haystack.last()?;
+        (self.predicate)(byte).then_some(1)
+    }
+}
+
+impl AsciiPattern for u8 {
+    type Searcher = BytesSearcher<'static>;
+    #[inline]
+    fn into_searcher(self) -> Self::Searcher {
+        BytesSearcher::byte(self)
+    }
+}
+
+impl AsciiPattern for char {
+    type Searcher = BytesSearcher<'static>;
+    #[inline]
+    fn into_searcher(self) -> Self::Searcher {
+        BytesSearcher::char(self)
+    }
+}
+
+impl AsciiPattern for AsciiChar {
+    type Searcher = BytesSearcher<'static>;
+    #[inline]
+    fn into_searcher(self) -> Self::Searcher {
+        BytesSearcher::byte(self.as_byte())
+    }
+}
+
+impl<'a> AsciiPattern for &'a str {
+    type Searcher = BytesSearcher<'a>;
+    #[inline]
+    fn into_searcher(self) -> Self::Searcher {
+        BytesSearcher::str(self)
+    }
+}
+
+impl<'a> AsciiPattern for &'a String {
+    type Searcher = BytesSearcher<'a>;
+    #[inline]
+    fn into_searcher(self) -> Self::Searcher {
+        BytesSearcher::str(self.as_str())
+    }
+}
+
+impl<'a> AsciiPattern for &'a AsciiStr {
+    type Searcher = BytesSearcher<'a>;
+    #[inline]
+    fn into_searcher(self) -> Self::Searcher {
+        BytesSearcher::bytes(self.as_bytes())
+    }
+}
+
+impl<'a> AsciiPattern for &'a AsciiString {
+    type Searcher = BytesSearcher<'a>;
+    #[inline]
+    fn into_searcher(self) -> Self::Searcher {
+        BytesSearcher::bytes(self.as_bytes())
+    }
+}
+
+impl<'a> AsciiPattern for &'a [u8] {
+    type Searcher = BytesSearcher<'a>;
+    #[inline]
+    fn into_searcher(self) -> Self::Searcher {
+        BytesSearcher::bytes(self)
+    }
+}
+
+impl<'a, const N: usize> AsciiPattern for &'a [u8; N] {
+    type Searcher = BytesSearcher<'a>;
+    #[inline]
+    fn into_searcher(self) -> Self::Searcher {
+        BytesSearcher::bytes(self)
+    }
+}
+
+impl<F: FnMut(u8) -> bool> AsciiPattern for F {
+    type Searcher = PredicateSearcher<F>;
+    #[inline]
+    fn into_searcher(self) -> Self::Searcher {
+        PredicateSearcher { predicate: self }
+    }
+}
+
+/// An iterator over the non-overlapping matches of a pattern, and their byte offsets.
+/// <br>
+/// Created by `AsciiStr::match_indices`.
+#[derive(Debug, Clone)]
+pub struct MatchIndices<'a, S> {
+    haystack: &'a AsciiStr,
+    searcher: S,
+    position: usize,
+}
+
+impl<'a, S: AsciiSearcher> MatchIndices<'a, S> {
+    pub(crate) fn new(haystack: &'a AsciiStr, searcher: S) -> Self {
+        Self {
+            haystack,
+            searcher,
+            position: 0,
+        }
+    }
+}
+
+impl<'a, S: AsciiSearcher> Iterator for MatchIndices<'a, S> {
+    type Item = (usize, &'a AsciiStr);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.haystack.as_bytes();
+        let found = self.searcher.find_from(bytes, self.position);
+        match found {
+            Some(range) => {
+                // empty matches must still advance, or the iterator would never end.
+                self.position = if range.is_empty() {
+                    range.end + 1
+                } else {
+                    range.end
+                };
+                Some((range.start, AsciiStr::from_bytes(&bytes[range])))
+            }
+            None => {
+                self.position = bytes.len() + 1;
+                None
+            }
+        }
+    }
+}
+
+/// An iterator over the byte offsets of the non-overlapping matches of a pattern.
+/// <br>
+/// Created by `AsciiStr::find_all`.
+#[derive(Debug, Clone)]
+pub struct FindAll<'a, S> {
+    inner: MatchIndices<'a, S>,
+}
+
+impl<'a, S: AsciiSearcher> FindAll<'a, S> {
+    pub(crate) fn new(haystack: &'a AsciiStr, searcher: S) -> Self {
+        Self {
+            inner: MatchIndices::new(haystack, searcher),
+        }
+    }
+}
+
+impl<S: AsciiSearcher> Iterator for FindAll<'_, S> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(inx, _)| inx)
+    }
+}
+
+const WORD_SIZE: usize = std::mem::size_of::<usize>();
+const LO_BITS: usize = usize::MAX / 255;
+const HI_BITS: usize = LO_BITS * 128;
+
+/// Returns true if any byte of the word is zero.
+#[inline(always)]
+const fn has_zero_byte(word: usize) -> bool {
+    word.wrapping_sub(LO_BITS) & !word & HI_BITS != 0
+}
+
+/// Returns the index of the first occurrence of `needle` in `haystack`, checking a word at a time.
+pub(crate) fn memchr(needle: u8, haystack: &[u8]) -> Option<usize> {
+    let repeated = LO_BITS * needle as usize;
+    let mut chunks = haystack.chunks_exact(WORD_SIZE);
+    let mut offset = 0;
+    for chunk in &mut chunks {
+        let word = usize::from_ne_bytes(chunk.try_into().unwrap());
+        if has_zero_byte(word ^ repeated) {
+            break;
+        }
+        offset += WORD_SIZE;
+    }
+    haystack[offset..]
+        .iter()
+        .position(|byte| *byte == needle)
+        .map(|inx| offset + inx)
+}
+
+/// Returns the index of the last occurrence of `needle` in `haystack`, checking a word at a time.
+pub(crate) fn memrchr(needle: u8, haystack: &[u8]) -> Option<usize> {
+    let repeated = LO_BITS * needle as usize;
+    let mut chunks = haystack.rchunks_exact(WORD_SIZE);
+    let mut end = haystack.len();
+    for chunk in &mut chunks {
+        let word = usize::from_ne_bytes(chunk.try_into().unwrap());
+        if has_zero_byte(word ^ repeated) {
+            break;
+        }
+        end -= WORD_SIZE;
+    }
+    haystack[..end].iter().rposition(|byte| *byte == needle)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn naive_find(haystack: &[u8], needle: &[u8], start: usize) -> Option<usize> {
+        (start..=haystack.len().saturating_sub(needle.len()))
+            .find(|inx| haystack[*inx..].starts_with(needle))
+    }
+
+    fn naive_rfind(haystack: &[u8], needle: &[u8], end: usize) -> Option<usize> {
+        (0..=end.checked_sub(needle.len())?)
+            .rev()
+            .find(|inx| haystack[*inx..end].starts_with(needle))
+    }
+
+    /// small deterministic generator, so the tests don't need a rand dependency.
+    fn pseudo_random_bytes(seed: u64, len: usize, alphabet: u8) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                b'a' + ((state >> 33) as u8 % alphabet)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_memchr() {
+        let mut haystack = vec![0u8; 100];
+        assert_eq!(memchr(1, &haystack), None);
+        assert_eq!(memrchr(1, &haystack), None);
+        for inx in 0..haystack.len() {
+            haystack[inx] = 1;
+            assert_eq!(memchr(1, &haystack), Some(inx));
+            assert_eq!(memrchr(1, &haystack), Some(inx));
+            haystack[inx] = 0;
+        }
+        assert_eq!(memchr(255, &[0, 255, 128, 255]), Some(1));
+        assert_eq!(memrchr(255, &[0, 255, 128, 255]), Some(3));
+    }
+
+    #[test]
+    fn test_bytes_searcher_matches_naive() {
+        for seed in 0..50 {
+            let haystack = pseudo_random_bytes(seed, 200, 3);
+            for needle_len in 0..6 {
+                let needle = pseudo_random_bytes(seed + 1000, needle_len, 3);
+                let mut searcher = BytesSearcher::bytes(&needle);
+                for start in [0, 1, 17, 199, 200] {
+                    assert_eq!(
+                        searcher.find_from(&haystack, start).map(|r| r.start),
+                        naive_find(&haystack, &needle, start),
+                    );
+                    assert_eq!(
+                        searcher.rfind_to(&haystack, start).map(|r| r.start),
+                        naive_rfind(&haystack, &needle, start),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_non_ascii_never_matches() {
+        let haystack = [0x80, 0xFF, b'a'];
+        assert_eq!('€'.into_searcher().find_from(&haystack, 0), None);
+        assert_eq!("a€".into_searcher().find_from(&haystack, 0), None);
+        assert_eq!("ÿa".into_searcher().find_from(&haystack, 0), Some(1..3));
+    }
+
+    #[test]
+    fn test_predicate() {
+        let haystack = b"ab1cd2";
+        let mut searcher = (|b: u8| b.is_ascii_digit()).into_searcher();
+        assert_eq!(searcher.find_from(haystack, 0), Some(2..3));
+        assert_eq!(searcher.find_from(haystack, 3), Some(5..6));
+        assert_eq!(searcher.rfind_to(haystack, 5), Some(2..3));
+        assert_eq!(searcher.prefix_len(haystack), None);
+        assert_eq!(searcher.suffix_len(haystack), Some(1));
+    }
+}
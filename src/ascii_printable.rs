@@ -0,0 +1,401 @@
+use crate::ascii_7bit::Ascii7String;
+use crate::ascii_error::AsciiError;
+use crate::ascii_group::AsciiGroup;
+use crate::ascii_str::AsciiStr;
+use crate::ascii_string::AsciiString;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Deref;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+macro_rules! printable_string {
+    ($(#[$attr:meta])* $name:ident, $ctrl:literal) => {
+        $(#[$attr])*
+        #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name {
+            inner: AsciiString,
+        }
+
+        impl $name {
+            /// Returns true if the byte is allowed in the string.
+            #[inline(always)]
+            pub fn is_allowed(byte: u8) -> bool {
+                match AsciiGroup::new(&byte) {
+                    AsciiGroup::Printable(_) => true,
+                    AsciiGroup::PrintableCtrl(_) => $ctrl,
+                    AsciiGroup::NonPrintableCtrl(_) | AsciiGroup::Extended(_) => false,
+                }
+            }
+            #[inline(always)]
+            fn is_allowed_char(character: char) -> bool {
+                u8::try_from(character).is_ok_and(|byte| byte.is_ascii() && Self::is_allowed(byte))
+            }
+            /// Returns an error for the first byte that is not allowed.
+            #[inline]
+            fn check(bytes: &[u8]) -> Result<(), AsciiError> {
+                match bytes.iter().position(|byte| !Self::is_allowed(*byte)) {
+                    None => Ok(()),
+                    Some(inx) => Err(AsciiError::non_printable(bytes[inx] as char, inx, inx)),
+                }
+            }
+            #[doc = concat!("Creates a new empty `", stringify!($name), "`.")]
+            #[inline]
+            pub fn new() -> Self {
+                Self::default()
+            }
+            #[doc = concat!("Creates a `", stringify!($name), "` from untrusted input, replacing any characters that are not allowed")]
+            /// with the `replacement` byte, or stripping them if `replacement` is `None`.
+            /// # Panics
+            /// * if the replacement byte is not allowed itself.
+            pub fn sanitize(string: &str, replacement: Option<u8>) -> Self {
+                if let Some(byte) = replacement {
+                    assert!(
+                        Self::is_allowed(byte),
+                        "replacement byte {byte:#04x} is not allowed"
+                    );
+                }
+                let mut inner = AsciiString::with_capacity(string.len());
+                for character in string.chars() {
+                    if Self::is_allowed_char(character) {
+                        inner.push(character as u8);
+                    } else if let Some(byte) = replacement {
+                        inner.push(byte);
+                    }
+                }
+                Self { inner }
+            }
+            #[doc = concat!("Creates a `", stringify!($name), "` from an `AsciiStr`, replacing any bytes that are not allowed")]
+            /// with the `replacement` byte, or stripping them if `replacement` is `None`.
+            /// # Panics
+            /// * if the replacement byte is not allowed itself.
+            pub fn sanitize_ascii(astr: &AsciiStr, replacement: Option<u8>) -> Self {
+                if let Some(byte) = replacement {
+                    assert!(
+                        Self::is_allowed(byte),
+                        "replacement byte {byte:#04x} is not allowed"
+                    );
+                }
+                let inner = astr
+                    .as_bytes()
+                    .iter()
+                    .filter_map(|byte| match Self::is_allowed(*byte) {
+                        true => Some(*byte),
+                        false => replacement,
+                    })
+                    .collect::<Vec<u8>>();
+                Self {
+                    inner: AsciiString::from(inner),
+                }
+            }
+            /// Returns the contents as a `&str`, without copying.
+            #[inline]
+            pub fn as_str(&self) -> &str {
+                // SAFETY: every byte is printable 7-bit ASCII, which is valid UTF-8.
+                unsafe { std::str::from_utf8_unchecked(self.inner.as_bytes()) }
+            }
+            /// Returns the contents as an `AsciiStr`.
+            #[inline]
+            pub fn as_ascii_str(&self) -> &AsciiStr {
+                self.inner.as_ascii_str()
+            }
+            /// Converts into an `AsciiString`, without copying.
+            #[inline]
+            pub fn into_ascii_string(self) -> AsciiString {
+                self.inner
+            }
+            /// Pushes a character onto the end of the string.
+            /// # Errors
+            /// * if the character is not allowed.
+            pub fn try_push(&mut self, character: char) -> Result<(), AsciiError> {
+                if !Self::is_allowed_char(character) {
+                    return Err(AsciiError::non_printable(character, self.len(), self.len()));
+                }
+                self.inner.push(character as u8);
+                Ok(())
+            }
+            /// Pushes a string onto the end of the string.
+            /// # Errors
+            /// * if the string contains any characters that are not allowed (the string is left unchanged).
+            pub fn try_push_str(&mut self, string: &str) -> Result<(), AsciiError> {
+                if let Some((char_index, (byte_index, character))) = string
+                    .char_indices()
+                    .enumerate()
+                    .find(|(_, (_, character))| !Self::is_allowed_char(*character))
+                {
+                    return Err(AsciiError::non_printable(character, char_index, byte_index));
+                }
+                self.inner.push_bytes(string.as_bytes());
+                Ok(())
+            }
+            /// Removes the last character and returns it, or `None` if the string is empty.
+            #[inline]
+            pub fn pop(&mut self) -> Option<char> {
+                self.inner.pop().map(char::from)
+            }
+            /// Shortens the string to the specified length (does nothing if it is already shorter).
+            #[inline]
+            pub fn truncate(&mut self, len: usize) {
+                self.inner.truncate(len);
+            }
+            /// Removes all contents.
+            #[inline]
+            pub fn clear(&mut self) {
+                self.inner.clear();
+            }
+        }
+
+        impl Deref for $name {
+            type Target = AsciiStr;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                self.as_ascii_str()
+            }
+        }
+
+        impl AsRef<AsciiStr> for $name {
+            #[inline]
+            fn as_ref(&self) -> &AsciiStr {
+                self.as_ascii_str()
+            }
+        }
+
+        impl AsRef<str> for $name {
+            #[inline]
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            #[inline]
+            fn as_ref(&self) -> &[u8] {
+                self.inner.as_bytes()
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            #[inline]
+            fn eq(&self, other: &str) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            #[inline]
+            fn eq(&self, other: &&str) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = AsciiError;
+
+            #[inline]
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                let mut result = Self::new();
+                result.try_push_str(value)?;
+                Ok(result)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = AsciiError;
+
+            #[inline]
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                Self::try_from(value.as_str())
+            }
+        }
+
+        impl TryFrom<AsciiString> for $name {
+            type Error = AsciiError;
+
+            #[inline]
+            fn try_from(value: AsciiString) -> Result<Self, Self::Error> {
+                Self::check(value.as_bytes())?;
+                Ok(Self { inner: value })
+            }
+        }
+
+        impl TryFrom<&AsciiStr> for $name {
+            type Error = AsciiError;
+
+            #[inline]
+            fn try_from(value: &AsciiStr) -> Result<Self, Self::Error> {
+                Self::check(value.as_bytes())?;
+                Ok(Self {
+                    inner: value.to_owned(),
+                })
+            }
+        }
+
+        impl From<$name> for AsciiString {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.inner
+            }
+        }
+
+        impl From<$name> for Ascii7String {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value
+                    .inner
+                    .into_ascii7()
+                    .expect("printable characters are 7-bit ASCII")
+            }
+        }
+
+        impl From<$name> for String {
+            #[inline]
+            fn from(value: $name) -> Self {
+                // SAFETY: every byte is printable 7-bit ASCII, which is valid UTF-8.
+                unsafe { String::from_utf8_unchecked(value.inner.into_bytes()) }
+            }
+        }
+
+        impl Display for $name {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                Display::fmt(self.as_str(), f)
+            }
+        }
+
+        impl Debug for $name {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                Debug::fmt(self.as_str(), f)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let string = String::deserialize(deserializer)?;
+                Self::try_from(string).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+printable_string!(
+    /// An `AsciiString` guaranteed to only contain `AsciiGroup::Printable` characters (space to `~`).
+    /// <br>
+    /// Use it to safely display untrusted identifiers in logs, where control characters
+    /// (escape sequences, backspaces, line breaks) could forge or hide output.
+    /// * constructors reject any other characters, `sanitize()` strips or replaces them.
+    /// * derefs to `AsciiStr` (read only), so all the searching/splitting methods are available.
+    /// * printable ASCII is valid UTF-8, so `as_str()` borrows the bytes as a `&str` without copying.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let user = PrintableAsciiString::try_from("admin").unwrap();
+    /// assert_eq!(user.as_str(), "admin");
+    ///
+    /// let error = PrintableAsciiString::try_from("admin\x1b[2J").unwrap_err();
+    /// assert_eq!(error.kind(), AsciiErrorKind::NonPrintable);
+    /// assert_eq!(error.char_index(), 5);
+    ///
+    /// let user = PrintableAsciiString::sanitize("admin\x1b[2J\n", Some(b'?'));
+    /// assert_eq!(user.as_str(), "admin?[2J?");
+    /// let user = PrintableAsciiString::sanitize("admin\x1b[2J\n", None);
+    /// assert_eq!(user.as_str(), "admin[2J");
+    /// ```
+    PrintableAsciiString,
+    false
+);
+printable_string!(
+    /// A `PrintableAsciiString` which also allows `AsciiGroup::PrintableCtrl` characters (tab, LF and CR),
+    /// for multi-line text.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let multi_line = PrintableCtrlAsciiString::try_from("line 1\r\n\tline 2").unwrap();
+    /// assert_eq!(multi_line.lines().count(), 2);
+    /// assert!(PrintableCtrlAsciiString::try_from("bell\x07").is_err());
+    /// ```
+    PrintableCtrlAsciiString,
+    true
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ascii_error::AsciiErrorKind;
+
+    #[test]
+    fn test_allowed() {
+        for byte in 0..=255u8 {
+            let group = AsciiGroup::new(&byte);
+            assert_eq!(PrintableAsciiString::is_allowed(byte), group.is_printable());
+            assert_eq!(
+                PrintableCtrlAsciiString::is_allowed(byte),
+                group.is_printable() || group.is_printable_ctrl()
+            );
+        }
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(PrintableAsciiString::try_from("a\tb").is_err());
+        assert!(PrintableCtrlAsciiString::try_from("a\tb").is_ok());
+        let error = PrintableCtrlAsciiString::try_from("ab\x7F").unwrap_err();
+        assert_eq!(error.kind(), AsciiErrorKind::NonPrintable);
+        assert_eq!((error.character(), error.char_index()), ('\x7F', 2));
+        let error = PrintableCtrlAsciiString::try_from("é€").unwrap_err();
+        assert_eq!(error.character(), 'é');
+
+        let mut astring = AsciiString::try_from("abc").unwrap();
+        assert!(PrintableAsciiString::try_from(astring.as_ascii_str()).is_ok());
+        astring.push(0xA0);
+        let error = PrintableAsciiString::try_from(astring).unwrap_err();
+        assert_eq!((error.character(), error.byte_index()), ('\u{A0}', 3));
+    }
+
+    #[test]
+    fn test_sanitize() {
+        let input = "id\u{0}\u{202E}é\r\n";
+        assert_eq!(PrintableAsciiString::sanitize(input, None), "id");
+        assert_eq!(PrintableAsciiString::sanitize(input, Some(b'.')), "id.....");
+        assert_eq!(PrintableCtrlAsciiString::sanitize(input, None), "id\r\n");
+        let mut astring = AsciiString::try_from("id\té").unwrap();
+        astring.push(0x1B);
+        assert_eq!(
+            PrintableAsciiString::sanitize_ascii(&astring, Some(b'_')),
+            "id___"
+        );
+        assert_eq!(
+            PrintableCtrlAsciiString::sanitize_ascii(&astring, None),
+            "id\t"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sanitize_bad_replacement() {
+        PrintableAsciiString::sanitize("\n", Some(b'\n'));
+    }
+
+    #[test]
+    fn test_conversions() {
+        let mut printable = PrintableAsciiString::new();
+        printable.try_push_str("abc").unwrap();
+        assert!(printable.try_push('\n').is_err());
+        printable.try_push('d').unwrap();
+        assert_eq!(printable.pop(), Some('d'));
+        assert_eq!(format!("{printable} {printable:?}"), "abc \"abc\"");
+        let ascii7 = Ascii7String::from(printable.clone());
+        assert_eq!(ascii7.as_str(), "abc");
+        assert_eq!(String::from(printable), "abc");
+    }
+}
@@ -0,0 +1,599 @@
+use crate::ascii_char::AsciiChar;
+use crate::ascii_consts::{CR, LF};
+use crate::ascii_pattern::{memchr, memrchr, AsciiSearcher};
+use crate::ascii_str::AsciiStr;
+use std::ops::Range;
+
+/// Finds matches from both ends of the haystack without ever returning the same bytes twice.
+/// * forward matches start at or after `front`, backward matches end at or before `back`.
+/// * empty matches still advance, or splitting on an empty pattern would never end.
+#[derive(Debug, Clone)]
+struct Matcher<S> {
+    searcher: S,
+    front: usize,
+    back: usize,
+    exhausted: bool,
+}
+
+impl<S: AsciiSearcher> Matcher<S> {
+    fn new(searcher: S, len: usize) -> Self {
+        Self {
+            searcher,
+            front: 0,
+            back: len,
+            exhausted: false,
+        }
+    }
+
+    fn next_match(&mut self, haystack: &[u8]) -> Option<Range<usize>> {
+        if self.exhausted {
+            return None;
+        }
+        match self.searcher.find_from(&haystack[..self.back], self.front) {
+            Some(range) => {
+                if !range.is_empty() {
+                    self.front = range.end;
+                } else if range.end == self.back {
+                    self.exhausted = true;
+                } else {
+                    self.front = range.end + 1;
+                }
+                Some(range)
+            }
+            None => {
+                self.exhausted = true;
+                None
+            }
+        }
+    }
+
+    fn next_match_back(&mut self, haystack: &[u8]) -> Option<Range<usize>> {
+        if self.exhausted {
+            return None;
+        }
+        match self.searcher.rfind_to(haystack, self.back) {
+            Some(range) if range.start >= self.front => {
+                if !range.is_empty() {
+                    self.back = range.start;
+                } else if range.start == self.front {
+                    self.exhausted = true;
+                } else {
+                    self.back = range.start - 1;
+                }
+                Some(range)
+            }
+            _ => {
+                self.exhausted = true;
+                None
+            }
+        }
+    }
+}
+
+/// The state shared by all the pattern based split iterators.
+#[derive(Debug, Clone)]
+struct SplitInternal<'a, S> {
+    haystack: &'a [u8],
+    matcher: Matcher<S>,
+    start: usize,
+    end: usize,
+    allow_trailing_empty: bool,
+    finished: bool,
+}
+
+impl<'a, S: AsciiSearcher> SplitInternal<'a, S> {
+    fn new(haystack: &'a AsciiStr, searcher: S, allow_trailing_empty: bool) -> Self {
+        let haystack = haystack.as_bytes();
+        Self {
+            haystack,
+            matcher: Matcher::new(searcher, haystack.len()),
+            start: 0,
+            end: haystack.len(),
+            allow_trailing_empty,
+            finished: false,
+        }
+    }
+
+    #[inline]
+    fn get_end(&mut self) -> Option<&'a AsciiStr> {
+        if self.finished {
+            return None;
+        }
+        self.finished = true;
+        if self.allow_trailing_empty || self.end > self.start {
+            Some(AsciiStr::from_bytes(&self.haystack[self.start..self.end]))
+        } else {
+            None
+        }
+    }
+
+    fn next(&mut self) -> Option<&'a AsciiStr> {
+        if self.finished {
+            return None;
+        }
+        match self.matcher.next_match(self.haystack) {
+            Some(range) => {
+                let part = &self.haystack[self.start..range.start];
+                self.start = range.end;
+                Some(AsciiStr::from_bytes(part))
+            }
+            None => self.get_end(),
+        }
+    }
+
+    fn next_back(&mut self) -> Option<&'a AsciiStr> {
+        if self.finished {
+            return None;
+        }
+        if !self.allow_trailing_empty {
+            // the trailing part is only skipped when it is empty, and only once.
+            self.allow_trailing_empty = true;
+            match self.next_back() {
+                Some(part) if !part.is_empty() => return Some(part),
+                _ if self.finished => return None,
+                _ => {}
+            }
+        }
+        match self.matcher.next_match_back(self.haystack) {
+            Some(range) => {
+                let part = &self.haystack[range.end..self.end];
+                self.end = range.start;
+                Some(AsciiStr::from_bytes(part))
+            }
+            None => {
+                self.finished = true;
+                Some(AsciiStr::from_bytes(&self.haystack[self.start..self.end]))
+            }
+        }
+    }
+}
+
+/// An iterator over the parts of an `AsciiStr` separated by a pattern.
+/// <br>
+/// Created by `AsciiStr::split`.
+#[derive(Debug, Clone)]
+pub struct Split<'a, S> {
+    inner: SplitInternal<'a, S>,
+}
+
+impl<'a, S: AsciiSearcher> Split<'a, S> {
+    pub(crate) fn new(haystack: &'a AsciiStr, searcher: S) -> Self {
+        Self {
+            inner: SplitInternal::new(haystack, searcher, true),
+        }
+    }
+}
+
+impl<'a, S: AsciiSearcher> Iterator for Split<'a, S> {
+    type Item = &'a AsciiStr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<S: AsciiSearcher> DoubleEndedIterator for Split<'_, S> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+/// An iterator over the parts of an `AsciiStr` separated by a pattern, in reverse order.
+/// <br>
+/// Created by `AsciiStr::rsplit`.
+#[derive(Debug, Clone)]
+pub struct RSplit<'a, S> {
+    inner: SplitInternal<'a, S>,
+}
+
+impl<'a, S: AsciiSearcher> RSplit<'a, S> {
+    pub(crate) fn new(haystack: &'a AsciiStr, searcher: S) -> Self {
+        Self {
+            inner: SplitInternal::new(haystack, searcher, true),
+        }
+    }
+}
+
+impl<'a, S: AsciiSearcher> Iterator for RSplit<'a, S> {
+    type Item = &'a AsciiStr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<S: AsciiSearcher> DoubleEndedIterator for RSplit<'_, S> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+/// An iterator over at most `n` parts of an `AsciiStr` separated by a pattern.
+/// <br>
+/// Created by `AsciiStr::splitn`.
+#[derive(Debug, Clone)]
+pub struct SplitN<'a, S> {
+    inner: SplitInternal<'a, S>,
+    count: usize,
+}
+
+impl<'a, S: AsciiSearcher> SplitN<'a, S> {
+    pub(crate) fn new(haystack: &'a AsciiStr, searcher: S, count: usize) -> Self {
+        Self {
+            inner: SplitInternal::new(haystack, searcher, true),
+            count,
+        }
+    }
+}
+
+impl<'a, S: AsciiSearcher> Iterator for SplitN<'a, S> {
+    type Item = &'a AsciiStr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                // the last part is the remainder, separators included.
+                self.count = 0;
+                self.inner.get_end()
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next()
+            }
+        }
+    }
+}
+
+/// An iterator over the parts of an `AsciiStr` terminated by a pattern.
+/// <br>
+/// Same as `Split`, except a trailing empty part is skipped.
+/// <br>
+/// Created by `AsciiStr::split_terminator`.
+#[derive(Debug, Clone)]
+pub struct SplitTerminator<'a, S> {
+    inner: SplitInternal<'a, S>,
+}
+
+impl<'a, S: AsciiSearcher> SplitTerminator<'a, S> {
+    pub(crate) fn new(haystack: &'a AsciiStr, searcher: S) -> Self {
+        Self {
+            inner: SplitInternal::new(haystack, searcher, false),
+        }
+    }
+}
+
+impl<'a, S: AsciiSearcher> Iterator for SplitTerminator<'a, S> {
+    type Item = &'a AsciiStr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<S: AsciiSearcher> DoubleEndedIterator for SplitTerminator<'_, S> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+/// An iterator over the non-empty parts of an `AsciiStr` separated by bytes matching a predicate.
+#[derive(Debug, Clone)]
+struct SplitBytes<'a> {
+    remaining: &'a [u8],
+    is_separator: fn(u8) -> bool,
+}
+
+impl<'a> SplitBytes<'a> {
+    fn next(&mut self) -> Option<&'a AsciiStr> {
+        let is_separator = self.is_separator;
+        let start = self.remaining.iter().position(|b| !is_separator(*b))?;
+        let rest = &self.remaining[start..];
+        let end = rest
+            .iter()
+            .position(|b| is_separator(*b))
+            .unwrap_or(rest.len());
+        self.remaining = &rest[end..];
+        Some(AsciiStr::from_bytes(&rest[..end]))
+    }
+
+    fn next_back(&mut self) -> Option<&'a AsciiStr> {
+        let is_separator = self.is_separator;
+        let end = self.remaining.iter().rposition(|b| !is_separator(*b))? + 1;
+        let rest = &self.remaining[..end];
+        let start = rest
+            .iter()
+            .rposition(|b| is_separator(*b))
+            .map_or(0, |inx| inx + 1);
+        self.remaining = &rest[..start];
+        Some(AsciiStr::from_bytes(&rest[start..]))
+    }
+}
+
+/// An iterator over the whitespace separated parts of an `AsciiStr`.
+/// <br>
+/// Whitespace is as defined by `AsciiChar::is_whitespace` (which includes VT, NEL and no-break space).
+/// <br>
+/// Created by `AsciiStr::split_whitespace`.
+#[derive(Debug, Clone)]
+pub struct SplitWhitespace<'a> {
+    inner: SplitBytes<'a>,
+}
+
+impl<'a> SplitWhitespace<'a> {
+    pub(crate) fn new(haystack: &'a AsciiStr) -> Self {
+        Self {
+            inner: SplitBytes {
+                remaining: haystack.as_bytes(),
+                is_separator: |b| AsciiChar::from_byte(b).is_whitespace(),
+            },
+        }
+    }
+}
+
+impl<'a> Iterator for SplitWhitespace<'a> {
+    type Item = &'a AsciiStr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl DoubleEndedIterator for SplitWhitespace<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+/// An iterator over the ASCII whitespace separated parts of an `AsciiStr`.
+/// <br>
+/// Whitespace is as defined by `AsciiChar::is_ascii_whitespace` (HT, LF, FF, CR and space).
+/// <br>
+/// Created by `AsciiStr::split_ascii_whitespace`.
+#[derive(Debug, Clone)]
+pub struct SplitAsciiWhitespace<'a> {
+    inner: SplitBytes<'a>,
+}
+
+impl<'a> SplitAsciiWhitespace<'a> {
+    pub(crate) fn new(haystack: &'a AsciiStr) -> Self {
+        Self {
+            inner: SplitBytes {
+                remaining: haystack.as_bytes(),
+                is_separator: |b| AsciiChar::from_byte(b).is_ascii_whitespace(),
+            },
+        }
+    }
+}
+
+impl<'a> Iterator for SplitAsciiWhitespace<'a> {
+    type Item = &'a AsciiStr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl DoubleEndedIterator for SplitAsciiWhitespace<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+/// An iterator over the lines of an `AsciiStr`.
+/// <br>
+/// Lines are terminated by `\n` or `\r\n`, which are not included in the yielded lines
+/// (the same as `AsciiStreamReader::read_line`).
+/// * a final line without a terminator is still yielded.
+/// * a `\r` not followed by `\n` is kept.
+///
+/// Created by `AsciiStr::lines`.
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    remaining: &'a [u8],
+}
+
+impl<'a> Lines<'a> {
+    pub(crate) fn new(haystack: &'a AsciiStr) -> Self {
+        Self {
+            remaining: haystack.as_bytes(),
+        }
+    }
+
+    #[inline]
+    fn strip_cr(line: &[u8]) -> &AsciiStr {
+        AsciiStr::from_bytes(line.strip_suffix(&[CR]).unwrap_or(line))
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a AsciiStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+        match memchr(LF, self.remaining) {
+            Some(inx) => {
+                let line = &self.remaining[..inx];
+                self.remaining = &self.remaining[inx + 1..];
+                Some(Self::strip_cr(line))
+            }
+            None => {
+                let line = self.remaining;
+                self.remaining = &[];
+                Some(AsciiStr::from_bytes(line))
+            }
+        }
+    }
+}
+
+impl DoubleEndedIterator for Lines<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (body, terminated) = match self.remaining.split_last() {
+            None => return None,
+            Some((&LF, body)) => (body, true),
+            Some(_) => (self.remaining, false),
+        };
+        let start = memrchr(LF, body).map_or(0, |inx| inx + 1);
+        self.remaining = &self.remaining[..start];
+        let line = &body[start..];
+        if terminated {
+            Some(Self::strip_cr(line))
+        } else {
+            Some(AsciiStr::from_bytes(line))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    fn to_strings<'a>(iter: impl Iterator<Item = &'a AsciiStr>) -> Vec<String> {
+        iter.map(String::from).collect()
+    }
+
+    #[test]
+    fn test_split_matches_str() {
+        let samples = [
+            "",
+            ",",
+            ",,",
+            "a",
+            "a,b",
+            ",a,b,",
+            "a,,b",
+            "abc,,def,",
+            "é,ü,,ñ",
+        ];
+        for sample in samples {
+            let astring = AsciiString::try_from(sample).unwrap();
+            let expected: Vec<&str> = sample.split(',').collect();
+            assert_eq!(to_strings(astring.split(',')), expected, "{sample:?}");
+            let expected: Vec<&str> = sample.rsplit(',').collect();
+            assert_eq!(to_strings(astring.rsplit(',')), expected, "{sample:?}");
+            let expected: Vec<&str> = sample.split_terminator(',').collect();
+            assert_eq!(
+                to_strings(astring.split_terminator(',')),
+                expected,
+                "{sample:?}"
+            );
+            let expected: Vec<&str> = sample.split_terminator(',').rev().collect();
+            assert_eq!(
+                to_strings(astring.split_terminator(',').rev()),
+                expected,
+                "{sample:?}"
+            );
+            for n in 0..5 {
+                let expected: Vec<&str> = sample.splitn(n, ',').collect();
+                assert_eq!(to_strings(astring.splitn(n, ',')), expected, "{sample:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_sequences() {
+        let samples = ["", "ab", "abab", "aXYbXYXYc", "XYXY", "XXYY", "aXYb"];
+        for sample in samples {
+            let astring = AsciiString::try_from(sample).unwrap();
+            for pattern in ["XY", "", "XYX"] {
+                let expected: Vec<&str> = sample.split(pattern).collect();
+                assert_eq!(to_strings(astring.split(pattern)), expected);
+                let expected: Vec<&str> = sample.rsplit(pattern).collect();
+                assert_eq!(to_strings(astring.rsplit(pattern)), expected);
+            }
+        }
+    }
+
+    fn text(part: Option<&AsciiStr>) -> Option<&str> {
+        part.map(|part| std::str::from_utf8(part.as_bytes()).unwrap())
+    }
+
+    #[test]
+    fn test_split_both_ends() {
+        let astring = AsciiString::try_from("a,b,c,d").unwrap();
+        let mut iter = astring.split(',');
+        assert_eq!(text(iter.next()), Some("a"));
+        assert_eq!(text(iter.next_back()), Some("d"));
+        assert_eq!(text(iter.next()), Some("b"));
+        assert_eq!(text(iter.next_back()), Some("c"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let astring = AsciiString::try_from("abc").unwrap();
+        let mut iter = astring.split("");
+        assert_eq!(text(iter.next()), Some(""));
+        assert_eq!(text(iter.next_back()), Some(""));
+        assert_eq!(text(iter.next()), Some("a"));
+        assert_eq!(text(iter.next_back()), Some("c"));
+        assert_eq!(text(iter.next()), Some("b"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_split_whitespace() {
+        let sample = " \t a\u{0B}b\u{85}c\u{A0}d \r\n e\u{0C}";
+        let astring = AsciiString::try_from(sample).unwrap();
+        let expected: Vec<&str> = sample.split_whitespace().collect();
+        assert_eq!(to_strings(astring.split_whitespace()), expected);
+        let expected: Vec<&str> = sample.split_whitespace().rev().collect();
+        assert_eq!(to_strings(astring.split_whitespace().rev()), expected);
+        let expected: Vec<&str> = sample.split_ascii_whitespace().collect();
+        assert_eq!(to_strings(astring.split_ascii_whitespace()), expected);
+        let expected: Vec<&str> = sample.split_ascii_whitespace().rev().collect();
+        assert_eq!(to_strings(astring.split_ascii_whitespace().rev()), expected);
+
+        let astring = AsciiString::try_from(" \t ").unwrap();
+        assert_eq!(astring.split_whitespace().next(), None);
+        assert_eq!(astring.split_ascii_whitespace().next_back(), None);
+    }
+
+    #[test]
+    fn test_lines() {
+        let samples = [
+            "",
+            "\n",
+            "\r\n",
+            "a",
+            "a\n",
+            "a\r\nb",
+            "a\n\nb\r\n",
+            "a\rb\r",
+            "\r\r\n\r",
+            "one\r\ntwo\nthree",
+        ];
+        for sample in samples {
+            let astring = AsciiString::try_from(sample).unwrap();
+            let expected: Vec<&str> = sample.lines().collect();
+            assert_eq!(to_strings(astring.lines()), expected, "{sample:?}");
+            let expected: Vec<&str> = sample.lines().rev().collect();
+            assert_eq!(to_strings(astring.lines().rev()), expected, "{sample:?}");
+        }
+    }
+
+    #[test]
+    fn test_lines_match_read_line() {
+        let sample = "one\r\ntwo\n\nthree\rfour\r\n\r\nfive";
+        let astring = AsciiString::try_from(sample).unwrap();
+        let mut reader = AsciiStreamReader::new(sample.as_bytes());
+        let mut buf = AsciiString::new();
+        let mut lines = astring.lines();
+        while let ReadLineResult::Success(_) = reader.read_line(&mut buf) {
+            assert_eq!(lines.next(), Some(buf.as_ascii_str()));
+        }
+        assert_eq!(lines.next(), None);
+    }
+}
@@ -0,0 +1,1205 @@
+use crate::ascii_case::CaseMode;
+use crate::ascii_char::AsciiChar;
+use crate::ascii_consts::ASCII_7_WHITESPACE;
+use crate::ascii_error::AsciiError;
+use crate::ascii_group::AsciiGroupIter;
+use crate::ascii_pattern::{AsciiPattern, AsciiSearcher, FindAll, MatchIndices};
+use crate::ascii_split::{
+    Lines, RSplit, Split, SplitAsciiWhitespace, SplitN, SplitTerminator, SplitWhitespace,
+};
+use crate::ascii_string::AsciiString;
+use crate::ascii_traits::{AsciiOrdToChar, CharToAsciiOrd, ToAsciiBytes};
+use crate::ascii_translators::write_latin1;
+use std::borrow::{Cow, ToOwned};
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::iter::Map;
+use std::ops::{
+    Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
+use std::slice::SliceIndex;
+use std::slice::{Iter, IterMut};
+
+/// A borrowed slice of ASCII and Extended ASCII characters.
+/// <br>
+/// `AsciiStr` is to `AsciiString` what `str` is to `String`. It is always used behind a reference (`&AsciiStr`),
+/// and can be created from any byte slice without copying, since every u8 value is a valid (Extended) ASCII character.
+/// # samples
+/// ```
+/// use cj_ascii::prelude::*;
+///
+/// let bytes = [72, 101, 108, 108, 111];
+/// let astr = AsciiStr::from_bytes(&bytes);
+/// assert_eq!(astr.len(), 5);
+/// assert_eq!(astr.to_string(), "Hello");
+/// ```
+/// Functions taking `&AsciiStr` accept both slices and `&AsciiString`.
+/// ```
+/// # use cj_ascii::prelude::*;
+/// fn count_l(text: &AsciiStr) -> usize {
+///     text.iter().filter(|b| **b == b'l').count()
+/// }
+///
+/// let astring = AsciiString::try_from("Hello World!").unwrap();
+/// assert_eq!(count_l(&astring), 3);
+/// assert_eq!(count_l(AsciiStr::from_bytes(b"llama")), 2);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct AsciiStr {
+    pub(crate) bytes: [u8],
+}
+
+impl AsciiStr {
+    /// Creates an `AsciiStr` from a byte slice, without copying.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astr = AsciiStr::from_bytes(b"ABC");
+    /// assert_eq!(astr.to_string(), "ABC");
+    /// ```
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> &AsciiStr {
+        // SAFETY: AsciiStr is repr(transparent) over [u8], and all u8 values are valid.
+        unsafe { &*(bytes as *const [u8] as *const AsciiStr) }
+    }
+    /// Creates a mutable `AsciiStr` from a mutable byte slice, without copying.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut bytes = [65, 66, 67];
+    /// let astr = AsciiStr::from_bytes_mut(&mut bytes);
+    /// astr[0] = b'X';
+    /// assert_eq!(bytes, [88, 66, 67]);
+    /// ```
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> &mut AsciiStr {
+        // SAFETY: AsciiStr is repr(transparent) over [u8], and all u8 values are valid.
+        unsafe { &mut *(bytes as *mut [u8] as *mut AsciiStr) }
+    }
+    /// Returns the length of the `AsciiStr` in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+    /// Returns true if the `AsciiStr` contains no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+    /// Returns a byte slice of the raw `AsciiStr`.
+    ///
+    /// * Each byte represents a single char.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+    /// Returns the `AsciiStr` as a `&str` without copying, or `None` if it contains Extended ASCII.
+    /// <br>
+    /// 7-bit ASCII (0x00..=0x7F) is already valid UTF-8, so no conversion is needed;
+    /// Extended ASCII needs two UTF-8 bytes per char, so use to_string() instead.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("abc").unwrap();
+    /// assert_eq!(astring.as_str(), Some("abc"));
+    /// let astring = AsciiString::try_from("abç").unwrap();
+    /// assert_eq!(astring.as_str(), None);
+    /// assert_eq!(astring.to_string(), "abç");
+    /// ```
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        if self.bytes.is_ascii() {
+            // SAFETY: 7-bit ASCII is valid UTF-8.
+            Some(unsafe { std::str::from_utf8_unchecked(&self.bytes) })
+        } else {
+            None
+        }
+    }
+    /// Returns a mutable byte slice of the raw `AsciiStr`.
+    ///
+    /// * Each byte represents a single char.
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+    /// Returns the `AsciiStr` as a slice of `AsciiChar`s.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("AB").unwrap();
+    /// assert_eq!(
+    ///     astring.as_ascii_chars(),
+    ///     [AsciiChar::LATIN_CAPITAL_LETTER_A, AsciiChar::LATIN_CAPITAL_LETTER_B]
+    /// );
+    /// ```
+    #[inline]
+    pub fn as_ascii_chars(&self) -> &[AsciiChar] {
+        // SAFETY: AsciiChar is repr(u8) and has a variant for every u8 value.
+        unsafe { &*(&self.bytes as *const [u8] as *const [AsciiChar]) }
+    }
+    /// Returns the `AsciiStr` as a mutable slice of `AsciiChar`s.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("AB").unwrap();
+    /// astring.as_ascii_chars_mut()[1] = AsciiChar::DIGIT_TWO;
+    /// assert_eq!(astring.to_string(), "A2");
+    /// ```
+    #[inline]
+    pub fn as_ascii_chars_mut(&mut self) -> &mut [AsciiChar] {
+        // SAFETY: AsciiChar is repr(u8) and has a variant for every u8 value.
+        unsafe { &mut *(&mut self.bytes as *mut [u8] as *mut [AsciiChar]) }
+    }
+    /// Returns the `AsciiChar` at the given index, or `None` if the index is out of bounds.
+    #[inline]
+    pub fn get_ascii_char(&self, index: usize) -> Option<AsciiChar> {
+        self.bytes.get(index).map(AsciiChar::from)
+    }
+    /// Returns the `AsciiStr` slice for the given byte range, or `None` if the range is out of bounds.
+    /// <br>
+    /// Accepts any range type (`a..b`, `a..`, `..b`, `a..=b`, `..=b`, `..`).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let record = AsciiString::try_from("0042SMITH     JOHN").unwrap();
+    /// assert_eq!(record.get(4..14).unwrap().trim_end().to_string(), "SMITH");
+    /// assert_eq!(record.get(14..).unwrap().to_string(), "JOHN");
+    /// assert!(record.get(14..30).is_none());
+    /// ```
+    #[inline]
+    pub fn get<R: SliceIndex<[u8], Output = [u8]>>(&self, range: R) -> Option<&AsciiStr> {
+        self.bytes.get(range).map(AsciiStr::from_bytes)
+    }
+    /// Returns the mutable `AsciiStr` slice for the given byte range, or `None` if the range is out of bounds.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("abcdef").unwrap();
+    /// astring.get_mut(..3).unwrap().make_uppercase(CaseMode::Ascii7);
+    /// assert_eq!(astring.to_string(), "ABCdef");
+    /// ```
+    #[inline]
+    pub fn get_mut<R: SliceIndex<[u8], Output = [u8]>>(
+        &mut self,
+        range: R,
+    ) -> Option<&mut AsciiStr> {
+        self.bytes.get_mut(range).map(AsciiStr::from_bytes_mut)
+    }
+    /// Copies the given byte range into a new `AsciiString`.
+    /// # Panics
+    /// * If the range is out of bounds (use `get` for a non-panicking version).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let record = AsciiString::try_from("0042SMITH     JOHN").unwrap();
+    /// let id = record.substring(..4);
+    /// assert_eq!(id.to_string(), "0042");
+    /// // or, borrowed
+    /// assert_eq!(record[..4], id);
+    /// ```
+    #[inline]
+    pub fn substring<R: SliceIndex<[u8], Output = [u8]>>(&self, range: R) -> AsciiString {
+        AsciiString::from(&self.bytes[range])
+    }
+    /// Copies the `AsciiStr` into a new `AsciiString`.
+    #[inline]
+    pub fn to_ascii_string(&self) -> AsciiString {
+        AsciiString::from(&self.bytes)
+    }
+    /// Sorts the `AsciiStr` in place.
+    /// # Example
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("DCBA").unwrap();
+    /// astring.sort();
+    /// assert_eq!(astring.to_string(), "ABCD");
+    /// ```
+    pub fn sort(&mut self) {
+        self.bytes.sort_unstable();
+    }
+    /// Returns true if the `AsciiStr` contains the given char or byte.
+    /// # Panics
+    /// * If a char is supplied and is not ASCII/Extended ASCII.
+    /// * using byte (u8) will never panic.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("Hello World!").unwrap();
+    /// assert!(astring.contains('H'));
+    /// assert!(astring.contains(72));
+    /// // assert!(!astring.contains('€')); // Will panic!
+    /// ```
+    pub fn contains<T: CharToAsciiOrd>(&self, value: T) -> bool {
+        self.bytes.contains(&value.ascii_ord_unchecked())
+    }
+    /// Returns the byte index of the first match of the pattern, or `None` if there is no match.
+    /// <br>
+    /// The pattern can be a u8, char, AsciiChar, &str, &AsciiStr, &AsciiString, byte slice, or a `FnMut(u8) -> bool` closure.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("Hello World!").unwrap();
+    /// assert_eq!(astring.find('o'), Some(4));
+    /// assert_eq!(astring.find(87), Some(6));
+    /// assert_eq!(astring.find("World"), Some(6));
+    /// assert_eq!(astring.find(|b: u8| b.is_ascii_punctuation()), Some(11));
+    /// assert_eq!(astring.find("€"), None);
+    /// ```
+    #[inline]
+    pub fn find<P: AsciiPattern>(&self, pattern: P) -> Option<usize> {
+        pattern
+            .into_searcher()
+            .find_from(&self.bytes, 0)
+            .map(|range| range.start)
+    }
+    /// Returns the byte index of the last match of the pattern, or `None` if there is no match.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("Hello World!").unwrap();
+    /// assert_eq!(astring.rfind('o'), Some(7));
+    /// assert_eq!(astring.rfind("l"), Some(9));
+    /// assert_eq!(astring.rfind("lo"), Some(3));
+    /// ```
+    #[inline]
+    pub fn rfind<P: AsciiPattern>(&self, pattern: P) -> Option<usize> {
+        pattern
+            .into_searcher()
+            .rfind_to(&self.bytes, self.bytes.len())
+            .map(|range| range.start)
+    }
+    /// Returns an iterator over the byte indexes of all non-overlapping matches of the pattern.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("abcXXabcXXabc").unwrap();
+    /// let found: Vec<usize> = astring.find_all("abc").collect();
+    /// assert_eq!(found, [0, 5, 10]);
+    /// ```
+    #[inline]
+    pub fn find_all<P: AsciiPattern>(&self, pattern: P) -> FindAll<'_, P::Searcher> {
+        FindAll::new(self, pattern.into_searcher())
+    }
+    /// Returns an iterator over all non-overlapping matches of the pattern, along with their byte indexes.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("a1b22c333").unwrap();
+    /// let mut iter = astring.match_indices(|b: u8| b.is_ascii_digit());
+    /// let (inx, found) = iter.next().unwrap();
+    /// assert_eq!(inx, 1);
+    /// assert_eq!(found.to_string(), "1");
+    /// assert_eq!(iter.count(), 5);
+    /// ```
+    #[inline]
+    pub fn match_indices<P: AsciiPattern>(&self, pattern: P) -> MatchIndices<'_, P::Searcher> {
+        MatchIndices::new(self, pattern.into_searcher())
+    }
+    /// Returns true if the `AsciiStr` starts with the pattern.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("Hello World!").unwrap();
+    /// assert!(astring.starts_with("Hello"));
+    /// assert!(astring.starts_with('H'));
+    /// assert!(!astring.starts_with("World"));
+    /// ```
+    #[inline]
+    pub fn starts_with<P: AsciiPattern>(&self, pattern: P) -> bool {
+        pattern.into_searcher().prefix_len(&self.bytes).is_some()
+    }
+    /// Returns true if the `AsciiStr` ends with the pattern.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("Hello World!").unwrap();
+    /// assert!(astring.ends_with("World!"));
+    /// assert!(astring.ends_with(33));
+    /// assert!(!astring.ends_with("Hello"));
+    /// ```
+    #[inline]
+    pub fn ends_with<P: AsciiPattern>(&self, pattern: P) -> bool {
+        pattern.into_searcher().suffix_len(&self.bytes).is_some()
+    }
+    /// Returns true if the `AsciiStr` contains a match of the pattern.
+    /// <br>
+    /// Unlike `contains`, this accepts any `AsciiPattern` (sequences, closures...), and never panics.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("Hello World!").unwrap();
+    /// assert!(astring.contains_seq("lo W"));
+    /// assert!(!astring.contains_seq("low"));
+    /// assert!(!astring.contains_seq('€'));
+    /// ```
+    #[inline]
+    pub fn contains_seq<P: AsciiPattern>(&self, pattern: P) -> bool {
+        self.find(pattern).is_some()
+    }
+    /// Returns a copy of the `AsciiStr` with all letters converted to uppercase.
+    /// <br>
+    /// `CaseMode::Ascii7` only maps `a`-`z`, while `CaseMode::Latin1` also maps the Extended ASCII letters (see `CaseMode`).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("café straße").unwrap();
+    /// assert_eq!(astring.to_uppercase(CaseMode::Ascii7).to_string(), "CAFé STRAßE");
+    /// assert_eq!(astring.to_uppercase(CaseMode::Latin1).to_string(), "CAFÉ STRAßE");
+    /// ```
+    #[inline]
+    pub fn to_uppercase(&self, mode: CaseMode) -> AsciiString {
+        let mut result = self.to_ascii_string();
+        result.make_uppercase(mode);
+        result
+    }
+    /// Returns a copy of the `AsciiStr` with all letters converted to lowercase.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("ÀÉÎ-AEI").unwrap();
+    /// assert_eq!(astring.to_lowercase(CaseMode::Ascii7).to_string(), "ÀÉÎ-aei");
+    /// assert_eq!(astring.to_lowercase(CaseMode::Latin1).to_string(), "àéî-aei");
+    /// ```
+    #[inline]
+    pub fn to_lowercase(&self, mode: CaseMode) -> AsciiString {
+        let mut result = self.to_ascii_string();
+        result.make_lowercase(mode);
+        result
+    }
+    /// Converts all letters to uppercase in place.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("ÿes").unwrap();
+    /// astring.make_uppercase(CaseMode::Latin1);
+    /// assert_eq!(astring.to_string(), "ÿES"); // Ÿ is not Latin-1
+    /// ```
+    #[inline]
+    pub fn make_uppercase(&mut self, mode: CaseMode) {
+        match mode {
+            CaseMode::Ascii7 => self.bytes.make_ascii_uppercase(),
+            CaseMode::Latin1 => self
+                .bytes
+                .iter_mut()
+                .for_each(|byte| *byte = mode.to_uppercase(*byte)),
+        }
+    }
+    /// Converts all letters to lowercase in place.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("ÇA VA").unwrap();
+    /// astring.make_lowercase(CaseMode::Latin1);
+    /// assert_eq!(astring.to_string(), "ça va");
+    /// ```
+    #[inline]
+    pub fn make_lowercase(&mut self, mode: CaseMode) {
+        match mode {
+            CaseMode::Ascii7 => self.bytes.make_ascii_lowercase(),
+            CaseMode::Latin1 => self
+                .bytes
+                .iter_mut()
+                .for_each(|byte| *byte = mode.to_lowercase(*byte)),
+        }
+    }
+    /// Returns true if the two `AsciiStr`s are equal, ignoring case.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let a = AsciiString::try_from("Élan").unwrap();
+    /// let b = AsciiString::try_from("éLAN").unwrap();
+    /// assert!(!a.eq_ignore_case(&b, CaseMode::Ascii7));
+    /// assert!(a.eq_ignore_case(&b, CaseMode::Latin1));
+    /// ```
+    #[inline]
+    pub fn eq_ignore_case(&self, other: &AsciiStr, mode: CaseMode) -> bool {
+        self.bytes.len() == other.bytes.len()
+            && self
+                .bytes
+                .iter()
+                .zip(&other.bytes)
+                .all(|(a, b)| mode.eq_ignore_case(*a, *b))
+    }
+    /// Compares two `AsciiStr`s lexicographically, ignoring case.
+    /// <br>
+    /// See also `CaseInsensitive` and `Latin1CaseInsensitive`, for use as map keys or when sorting.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// # use std::cmp::Ordering;
+    /// let a = AsciiString::try_from("apple").unwrap();
+    /// let b = AsciiString::try_from("Banana").unwrap();
+    /// assert_eq!(a.cmp(&b), Ordering::Greater);
+    /// assert_eq!(a.cmp_ignore_case(&b, CaseMode::Ascii7), Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cmp_ignore_case(&self, other: &AsciiStr, mode: CaseMode) -> Ordering {
+        mode.cmp_ignore_case(&self.bytes, &other.bytes)
+    }
+    /// Returns the `AsciiStr` with leading and trailing whitespace removed.
+    /// <br>
+    /// Whitespace is as defined by `ASCII_WHITESPACE` (the same as `char::is_whitespace`).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from(" \tHello World!\r\n").unwrap();
+    /// assert_eq!(astring.trim().to_string(), "Hello World!");
+    /// ```
+    #[inline]
+    pub fn trim(&self) -> &AsciiStr {
+        self.trim_start().trim_end()
+    }
+    /// Returns the `AsciiStr` with leading whitespace removed.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("  Hello  ").unwrap();
+    /// assert_eq!(astring.trim_start().to_string(), "Hello  ");
+    /// ```
+    #[inline]
+    pub fn trim_start(&self) -> &AsciiStr {
+        self.trim_start_matches(|b: u8| AsciiChar::from_byte(b).is_whitespace())
+    }
+    /// Returns the `AsciiStr` with trailing whitespace removed.
+    /// <br>
+    /// Useful for space padded fixed-width fields.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("  Hello  ").unwrap();
+    /// assert_eq!(astring.trim_end().to_string(), "  Hello");
+    /// ```
+    #[inline]
+    pub fn trim_end(&self) -> &AsciiStr {
+        self.trim_end_matches(|b: u8| AsciiChar::from_byte(b).is_whitespace())
+    }
+    /// Returns the `AsciiStr` with leading and trailing ASCII whitespace removed.
+    /// <br>
+    /// Whitespace is as defined by `ASCII_7_WHITESPACE` (the same as `u8::is_ascii_whitespace`),
+    /// so Extended ASCII no-break spaces are kept.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from(" \u{A0}Hello\n").unwrap();
+    /// assert_eq!(astring.trim_ascii().to_string(), "\u{A0}Hello");
+    /// ```
+    #[inline]
+    pub fn trim_ascii(&self) -> &AsciiStr {
+        self.trim_matches(|b: u8| ASCII_7_WHITESPACE.contains(&b))
+    }
+    /// Returns the `AsciiStr` with all leading and trailing matches of the pattern removed.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("000123000").unwrap();
+    /// assert_eq!(astring.trim_matches('0').to_string(), "123");
+    /// assert_eq!(astring.trim_matches("00").to_string(), "01230");
+    /// assert_eq!(astring.trim_matches(|b: u8| ASCII_CTRL_PRINTABLES.contains(&b)).to_string(), "000123000");
+    /// ```
+    #[inline]
+    pub fn trim_matches<P: AsciiPattern>(&self, pattern: P) -> &AsciiStr {
+        let mut searcher = pattern.into_searcher();
+        let start = Self::prefix_matches_len(&mut searcher, &self.bytes);
+        let end = self.bytes.len() - Self::suffix_matches_len(&mut searcher, &self.bytes[start..]);
+        AsciiStr::from_bytes(&self.bytes[start..end])
+    }
+    /// Returns the `AsciiStr` with all leading matches of the pattern removed.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("000123000").unwrap();
+    /// assert_eq!(astring.trim_start_matches('0').to_string(), "123000");
+    /// ```
+    #[inline]
+    pub fn trim_start_matches<P: AsciiPattern>(&self, pattern: P) -> &AsciiStr {
+        let start = Self::prefix_matches_len(&mut pattern.into_searcher(), &self.bytes);
+        AsciiStr::from_bytes(&self.bytes[start..])
+    }
+    /// Returns the `AsciiStr` with all trailing matches of the pattern removed.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("000123000").unwrap();
+    /// assert_eq!(astring.trim_end_matches('0').to_string(), "000123");
+    /// ```
+    #[inline]
+    pub fn trim_end_matches<P: AsciiPattern>(&self, pattern: P) -> &AsciiStr {
+        let len = Self::suffix_matches_len(&mut pattern.into_searcher(), &self.bytes);
+        AsciiStr::from_bytes(&self.bytes[..self.bytes.len() - len])
+    }
+    /// Returns the total length of the consecutive matches at the start of the bytes.
+    fn prefix_matches_len<S: AsciiSearcher>(searcher: &mut S, bytes: &[u8]) -> usize {
+        let mut start = 0;
+        // empty matches would never advance.
+        while let Some(len @ 1..) = searcher.prefix_len(&bytes[start..]) {
+            start += len;
+        }
+        start
+    }
+    /// Returns the total length of the consecutive matches at the end of the bytes.
+    fn suffix_matches_len<S: AsciiSearcher>(searcher: &mut S, bytes: &[u8]) -> usize {
+        let mut end = bytes.len();
+        while let Some(len @ 1..) = searcher.suffix_len(&bytes[..end]) {
+            end -= len;
+        }
+        bytes.len() - end
+    }
+    /// Replaces all matches of the pattern with a char, u8, &str, `String`, `AsciiString` or `&AsciiStr`,
+    /// returning a new `AsciiString`.
+    /// # Panics
+    /// * If the replacement contains any non ASCII/Extended ASCII characters.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("a-b-c").unwrap();
+    /// assert_eq!(astring.replace('-', ", ").to_string(), "a, b, c");
+    /// assert_eq!(astring.replace("-b-", 'é').to_string(), "aéc");
+    /// assert_eq!(astring.replace(|b: u8| b.is_ascii_alphabetic(), "").to_string(), "--");
+    /// ```
+    #[inline]
+    pub fn replace<P: AsciiPattern, T: ToAsciiBytes>(&self, from: P, to: T) -> AsciiString {
+        self.replacen(from, to, usize::MAX)
+    }
+    /// Replaces the first `count` matches of the pattern with a char, u8, &str, `String`, `AsciiString` or `&AsciiStr`,
+    /// returning a new `AsciiString`.
+    /// # Panics
+    /// * If the replacement contains any non ASCII/Extended ASCII characters.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("a-b-c").unwrap();
+    /// assert_eq!(astring.replacen('-', 95, 1).to_string(), "a_b-c");
+    /// ```
+    pub fn replacen<P: AsciiPattern, T: ToAsciiBytes>(
+        &self,
+        from: P,
+        to: T,
+        count: usize,
+    ) -> AsciiString {
+        let to = to.ascii_bytes_unchecked();
+        let mut result = Vec::with_capacity(self.bytes.len());
+        let mut last = 0;
+        for (inx, found) in self.match_indices(from).take(count) {
+            result.extend_from_slice(&self.bytes[last..inx]);
+            result.extend_from_slice(&to);
+            last = inx + found.len();
+        }
+        result.extend_from_slice(&self.bytes[last..]);
+        AsciiString::from(result)
+    }
+    /// Returns an iterator over the parts of the `AsciiStr` separated by the pattern.
+    /// <br>
+    /// Behaves like `str::split`: leading, trailing and consecutive separators produce empty parts.
+    /// * the parts borrow from the `AsciiStr`; use `to_ascii_string` (or `to_owned`) for owned parts.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("a,b,,c").unwrap();
+    /// let parts: Vec<String> = astring.split(',').map(String::from).collect();
+    /// assert_eq!(parts, ["a", "b", "", "c"]);
+    ///
+    /// let owned: Vec<AsciiString> = astring.split(",,").map(AsciiStr::to_ascii_string).collect();
+    /// assert_eq!(owned.len(), 2);
+    /// assert_eq!(owned[1].to_string(), "c");
+    /// ```
+    #[inline]
+    pub fn split<P: AsciiPattern>(&self, pattern: P) -> Split<'_, P::Searcher> {
+        Split::new(self, pattern.into_searcher())
+    }
+    /// Returns an iterator over at most `n` parts of the `AsciiStr` separated by the pattern.
+    /// <br>
+    /// The last part holds the remainder of the `AsciiStr`.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("key=value=more").unwrap();
+    /// let parts: Vec<String> = astring.splitn(2, '=').map(String::from).collect();
+    /// assert_eq!(parts, ["key", "value=more"]);
+    /// ```
+    #[inline]
+    pub fn splitn<P: AsciiPattern>(&self, n: usize, pattern: P) -> SplitN<'_, P::Searcher> {
+        SplitN::new(self, pattern.into_searcher(), n)
+    }
+    /// Returns an iterator over the parts of the `AsciiStr` separated by the pattern, starting from the end.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("a.b.c").unwrap();
+    /// let parts: Vec<String> = astring.rsplit('.').map(String::from).collect();
+    /// assert_eq!(parts, ["c", "b", "a"]);
+    /// ```
+    #[inline]
+    pub fn rsplit<P: AsciiPattern>(&self, pattern: P) -> RSplit<'_, P::Searcher> {
+        RSplit::new(self, pattern.into_searcher())
+    }
+    /// Returns an iterator over the parts of the `AsciiStr` terminated by the pattern.
+    /// <br>
+    /// Same as `split`, except a trailing empty part is skipped.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("a;b;c;").unwrap();
+    /// let parts: Vec<String> = astring.split_terminator(';').map(String::from).collect();
+    /// assert_eq!(parts, ["a", "b", "c"]);
+    /// ```
+    #[inline]
+    pub fn split_terminator<P: AsciiPattern>(
+        &self,
+        pattern: P,
+    ) -> SplitTerminator<'_, P::Searcher> {
+        SplitTerminator::new(self, pattern.into_searcher())
+    }
+    /// Returns an iterator over the whitespace separated parts of the `AsciiStr`, skipping empty parts.
+    /// <br>
+    /// Whitespace is as defined by `char::is_whitespace`, which includes VT, NEL (`0x85`) and no-break space (`0xA0`).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from(" a\tb\u{A0}c  ").unwrap();
+    /// let parts: Vec<String> = astring.split_whitespace().map(String::from).collect();
+    /// assert_eq!(parts, ["a", "b", "c"]);
+    /// ```
+    #[inline]
+    pub fn split_whitespace(&self) -> SplitWhitespace<'_> {
+        SplitWhitespace::new(self)
+    }
+    /// Returns an iterator over the ASCII whitespace separated parts of the `AsciiStr`, skipping empty parts.
+    /// <br>
+    /// Whitespace is as defined by `u8::is_ascii_whitespace` (HT, LF, FF, CR and space).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from(" a\tb\u{A0}c  ").unwrap();
+    /// let parts: Vec<String> = astring.split_ascii_whitespace().map(String::from).collect();
+    /// assert_eq!(parts, ["a", "b\u{A0}c"]);
+    /// ```
+    #[inline]
+    pub fn split_ascii_whitespace(&self) -> SplitAsciiWhitespace<'_> {
+        SplitAsciiWhitespace::new(self)
+    }
+    /// Returns an iterator over the lines of the `AsciiStr`.
+    /// <br>
+    /// Lines are terminated by `\n` or `\r\n`, which are not included in the lines, the same as `AsciiStreamReader::read_line`.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("one\r\ntwo\n\nthree").unwrap();
+    /// let lines: Vec<String> = astring.lines().map(String::from).collect();
+    /// assert_eq!(lines, ["one", "two", "", "three"]);
+    /// ```
+    #[inline]
+    pub fn lines(&self) -> Lines<'_> {
+        Lines::new(self)
+    }
+    /// Returns a u8 iterator over the `AsciiStr`.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("Hello World!").unwrap();
+    /// let mut iter = astring.iter();
+    /// assert_eq!(iter.next(), Some(&72));
+    /// assert_eq!(iter.next(), Some(&101));
+    /// assert_eq!(iter.next(), Some(&108));
+    /// assert_eq!(iter.next(), Some(&108));
+    /// assert_eq!(iter.next(), Some(&111));
+    /// assert_eq!(iter.next(), Some(&32));
+    /// assert_eq!(iter.next(), Some(&87));
+    /// assert_eq!(iter.next(), Some(&111));
+    /// assert_eq!(iter.next(), Some(&114));
+    /// assert_eq!(iter.next(), Some(&108));
+    /// assert_eq!(iter.next(), Some(&100));
+    /// assert_eq!(iter.next(), Some(&33));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, u8> {
+        self.bytes.iter()
+    }
+    /// Returns a mutable u8 iterator over the `AsciiStr`.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("Hello World!").unwrap();
+    /// let mut iter = astring.iter_mut();
+    /// assert_eq!(iter.next(), Some(&mut 72));
+    /// assert_eq!(iter.next(), Some(&mut 101));
+    /// assert_eq!(iter.next(), Some(&mut 108));
+    /// assert_eq!(iter.next(), Some(&mut 108));
+    /// assert_eq!(iter.next(), Some(&mut 111));
+    /// assert_eq!(iter.next(), Some(&mut 32));
+    /// assert_eq!(iter.next(), Some(&mut 87));
+    /// assert_eq!(iter.next(), Some(&mut 111));
+    /// assert_eq!(iter.next(), Some(&mut 114));
+    /// assert_eq!(iter.next(), Some(&mut 108));
+    /// assert_eq!(iter.next(), Some(&mut 100));
+    /// assert_eq!(iter.next(), Some(&mut 33));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("Hello World!").unwrap();
+    /// for byte in astring.iter_mut() {
+    ///    if *byte == 32 {
+    ///       *byte = 95;
+    ///   }
+    /// }
+    /// assert_eq!(astring, AsciiString::try_from("Hello_World!").unwrap());
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, u8> {
+        self.bytes.iter_mut()
+    }
+    /// Returns a char iterator over the `AsciiStr`.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("Hello World!").unwrap();
+    /// let mut iter = astring.iter_ascii();
+    /// assert_eq!(iter.next(), Some('H'));
+    /// assert_eq!(iter.next(), Some('e'));
+    /// assert_eq!(iter.next(), Some('l'));
+    /// assert_eq!(iter.next(), Some('l'));
+    /// assert_eq!(iter.next(), Some('o'));
+    /// assert_eq!(iter.next(), Some(' '));
+    /// assert_eq!(iter.next(), Some('W'));
+    /// assert_eq!(iter.next(), Some('o'));
+    /// assert_eq!(iter.next(), Some('r'));
+    /// assert_eq!(iter.next(), Some('l'));
+    /// assert_eq!(iter.next(), Some('d'));
+    /// assert_eq!(iter.next(), Some('!'));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter_ascii(&self) -> Map<Iter<'_, u8>, fn(&u8) -> char> {
+        self.bytes.iter().map(|byte| byte.to_ascii_char())
+    }
+    /// Returns an `AsciiChar` iterator over the `AsciiStr`.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("Hi").unwrap();
+    /// let mut iter = astring.iter_ascii_chars();
+    /// assert_eq!(iter.next(), Some(AsciiChar::LATIN_CAPITAL_LETTER_H));
+    /// assert_eq!(iter.next(), Some(AsciiChar::LATIN_SMALL_LETTER_I));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter_ascii_chars(&self) -> std::iter::Copied<Iter<'_, AsciiChar>> {
+        self.as_ascii_chars().iter().copied()
+    }
+    /// Returns an AsciiGroup iterator over the `AsciiStr`.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("Hello World!").unwrap();
+    /// for x in astring.iter_ascii_group() {
+    ///     match x {
+    ///        AsciiGroup::PrintableCtrl(_) => println!("PrintableCtrl: {}", x.as_char()),
+    ///        AsciiGroup::Printable(_) => println!("PrintableAscii: {}", x.as_char()),
+    ///        AsciiGroup::NonPrintableCtrl(_) => println!("NonPrintableCtrl: {}", x.as_byte()),
+    ///        AsciiGroup::Extended(_) => println!("Extended: {}", x.as_byte()),
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn iter_ascii_group(&self) -> AsciiGroupIter<'_> {
+        AsciiGroupIter::new(self.iter())
+    }
+}
+
+impl Index<usize> for AsciiStr {
+    type Output = u8;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.bytes[index]
+    }
+}
+
+impl IndexMut<usize> for AsciiStr {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.bytes[index]
+    }
+}
+
+macro_rules! impl_range_index {
+    ($($range:ty),*) => {
+        $(
+            impl Index<$range> for AsciiStr {
+                type Output = AsciiStr;
+                #[inline]
+                fn index(&self, index: $range) -> &Self::Output {
+                    AsciiStr::from_bytes(&self.bytes[index])
+                }
+            }
+
+            impl IndexMut<$range> for AsciiStr {
+                #[inline]
+                fn index_mut(&mut self, index: $range) -> &mut Self::Output {
+                    AsciiStr::from_bytes_mut(&mut self.bytes[index])
+                }
+            }
+        )*
+    };
+}
+
+impl_range_index!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeInclusive<usize>,
+    RangeToInclusive<usize>,
+    RangeFull
+);
+
+impl ToOwned for AsciiStr {
+    type Owned = AsciiString;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        self.to_ascii_string()
+    }
+}
+
+impl AsRef<AsciiStr> for AsciiStr {
+    #[inline]
+    fn as_ref(&self) -> &AsciiStr {
+        self
+    }
+}
+
+impl AsRef<[u8]> for AsciiStr {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl AsRef<AsciiStr> for [u8] {
+    #[inline]
+    fn as_ref(&self) -> &AsciiStr {
+        AsciiStr::from_bytes(self)
+    }
+}
+
+impl<'a> From<&'a [u8]> for &'a AsciiStr {
+    #[inline]
+    fn from(value: &'a [u8]) -> Self {
+        AsciiStr::from_bytes(value)
+    }
+}
+
+impl<'a> From<&'a mut [u8]> for &'a mut AsciiStr {
+    #[inline]
+    fn from(value: &'a mut [u8]) -> Self {
+        AsciiStr::from_bytes_mut(value)
+    }
+}
+
+impl<'a> From<&'a AsciiStr> for &'a [u8] {
+    #[inline]
+    fn from(value: &'a AsciiStr) -> Self {
+        &value.bytes
+    }
+}
+
+impl Default for &AsciiStr {
+    #[inline]
+    fn default() -> Self {
+        AsciiStr::from_bytes(&[])
+    }
+}
+
+impl ToAsciiBytes for &AsciiStr {
+    #[inline(always)]
+    fn try_ascii_bytes(&self) -> Result<Cow<'_, [u8]>, AsciiError> {
+        Ok(Cow::Borrowed(&self.bytes))
+    }
+}
+
+impl PartialEq<AsciiString> for AsciiStr {
+    #[inline]
+    fn eq(&self, other: &AsciiString) -> bool {
+        self.bytes == other.bytes[..]
+    }
+}
+
+impl PartialEq<AsciiString> for &AsciiStr {
+    #[inline]
+    fn eq(&self, other: &AsciiString) -> bool {
+        self.bytes == other.bytes[..]
+    }
+}
+
+impl PartialEq<AsciiStr> for AsciiString {
+    #[inline]
+    fn eq(&self, other: &AsciiStr) -> bool {
+        self.bytes[..] == other.bytes
+    }
+}
+
+impl PartialEq<&AsciiStr> for AsciiString {
+    #[inline]
+    fn eq(&self, other: &&AsciiStr) -> bool {
+        self.bytes[..] == other.bytes
+    }
+}
+
+/// Copies 7-bit contents in one go; otherwise copies 7-bit runs in bulk, expanding only the Extended bytes,
+/// into a `String` sized exactly up front.
+impl From<&AsciiStr> for String {
+    fn from(value: &AsciiStr) -> Self {
+        if let Some(string) = value.as_str() {
+            return string.to_owned();
+        }
+        // each Extended byte is two UTF-8 bytes, so the String never has to grow.
+        let extended = value.bytes.iter().filter(|byte| !byte.is_ascii()).count();
+        let mut result = String::with_capacity(value.len() + extended);
+        write_latin1(&mut result, &value.bytes).expect("writing to a String never fails");
+        result
+    }
+}
+
+impl Display for AsciiStr {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_latin1(f, &self.bytes)
+    }
+}
+
+impl Debug for AsciiStr {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_latin1(f, &self.bytes)
+    }
+}
+
+/// Reads from the front of the `AsciiStr`, advancing the slice past the bytes read (like `&[u8]`).
+/// <br>
+/// The bytes are not moved, so this is the way to stream a large `AsciiString` into `io::copy`,
+/// a `BufReader` or a compressor.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// use std::io::{BufRead, Read};
+///
+/// let astring = AsciiString::try_from("line 1\nline 2").unwrap();
+/// let mut reader: &AsciiStr = &astring;
+/// let mut line = String::new();
+/// reader.read_line(&mut line).unwrap();
+/// assert_eq!(line, "line 1\n");
+/// let mut rest = Vec::new();
+/// reader.read_to_end(&mut rest).unwrap();
+/// assert_eq!(rest, b"line 2");
+/// assert!(reader.is_empty());
+/// ```
+impl std::io::Read for &AsciiStr {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut bytes = &self.bytes;
+        let read = bytes.read(buf)?;
+        *self = AsciiStr::from_bytes(bytes);
+        Ok(read)
+    }
+}
+
+impl std::io::BufRead for &AsciiStr {
+    #[inline]
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        Ok(&self.bytes)
+    }
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        *self = AsciiStr::from_bytes(&self.bytes[amt..]);
+    }
+}
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+impl Serialize for AsciiStr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&String::from(self))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_bytes() {
+        let bytes = [65, 66, 67];
+        let astr = AsciiStr::from_bytes(&bytes);
+        assert_eq!(astr.len(), 3);
+        assert_eq!(astr[0], 65);
+        assert_eq!(astr.as_bytes().as_ptr(), bytes.as_ptr());
+        assert_eq!(astr.to_string(), "ABC");
+    }
+
+    #[test]
+    fn test_from_bytes_mut() {
+        let mut bytes = [65, 66, 67];
+        let astr = AsciiStr::from_bytes_mut(&mut bytes);
+        astr[1] = b'Z';
+        astr.sort();
+        assert_eq!(bytes, [65, 67, 90]);
+    }
+
+    #[test]
+    fn test_deref_from_ascii_string() {
+        let astring = AsciiString::try_from("ABC").unwrap();
+        let astr: &AsciiStr = &astring;
+        assert_eq!(astr, &astring);
+        assert_eq!(astr.as_bytes(), [65, 66, 67]);
+        assert!(astr.contains('B'));
+    }
+
+    #[test]
+    fn test_to_owned() {
+        let astr = AsciiStr::from_bytes(b"ABC");
+        let astring = astr.to_owned();
+        assert_eq!(astring, *astr);
+        assert_eq!(astring.to_string(), "ABC");
+    }
+
+    #[test]
+    fn test_borrow() {
+        use std::collections::HashMap;
+        let mut map = HashMap::new();
+        map.insert(AsciiString::try_from("key").unwrap(), 1);
+        assert_eq!(map.get(AsciiStr::from_bytes(b"key")), Some(&1));
+    }
+
+    #[test]
+    fn test_ascii_chars() {
+        let mut bytes = [0, 65, 255];
+        let astr = AsciiStr::from_bytes_mut(&mut bytes);
+        assert_eq!(
+            astr.as_ascii_chars(),
+            [
+                AsciiChar::NULL,
+                AsciiChar::LATIN_CAPITAL_LETTER_A,
+                AsciiChar::LATIN_SMALL_LETTER_Y_WITH_DIAERESIS
+            ]
+        );
+        astr.as_ascii_chars_mut()[0] = AsciiChar::SPACE;
+        assert_eq!(astr.get_ascii_char(0), Some(AsciiChar::SPACE));
+        assert_eq!(astr.get_ascii_char(3), None);
+        assert_eq!(bytes, [32, 65, 255]);
+    }
+
+    #[test]
+    fn test_find() {
+        let astr = AsciiStr::from_bytes(b"one,two,,three");
+        assert_eq!(astr.find(','), Some(3));
+        assert_eq!(astr.rfind(','), Some(8));
+        assert_eq!(astr.find(",,"), Some(7));
+        assert_eq!(astr.find(AsciiStr::from_bytes(b"three")), Some(9));
+        assert_eq!(astr.find(b"four"), None);
+        assert_eq!(astr.find(""), Some(0));
+        assert_eq!(astr.rfind(""), Some(astr.len()));
+        let found: Vec<usize> = astr.find_all(',').collect();
+        assert_eq!(found, [3, 7, 8]);
+        let found: Vec<usize> = AsciiStr::from_bytes(b"aaaa").find_all("aa").collect();
+        assert_eq!(found, [0, 2]);
+        let found: Vec<usize> = AsciiStr::from_bytes(b"ab").find_all("").collect();
+        assert_eq!(found, [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_find_extended() {
+        let astring = AsciiString::try_from("café crème").unwrap();
+        assert_eq!(astring.find('é'), Some(3));
+        assert_eq!(astring.find("crè"), Some(5));
+        assert_eq!(astring.rfind(AsciiChar::LATIN_SMALL_LETTER_E), Some(9));
+        assert!(astring.starts_with(&AsciiString::try_from("caf").unwrap()));
+        assert!(astring.ends_with("ème"));
+    }
+
+    #[test]
+    fn test_empty() {
+        let astr: &AsciiStr = Default::default();
+        assert!(astr.is_empty());
+        assert_eq!(astr.len(), 0);
+        assert_eq!(astr.to_string(), "");
+    }
+
+    #[test]
+    fn test_iter_ascii_group() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let astr = AsciiStr::from_bytes(&bytes);
+        let mut c = 0u8;
+        for x in astr.iter_ascii_group() {
+            assert_eq!(x.as_byte(), c);
+            c = c.saturating_add(1);
+        }
+    }
+
+    #[test]
+    fn test_index_range() {
+        let mut astring = AsciiString::try_from("0123456789").unwrap();
+        assert_eq!(astring[2..5].as_bytes(), b"234");
+        assert_eq!(astring[7..].as_bytes(), b"789");
+        assert_eq!(astring[..2].as_bytes(), b"01");
+        assert_eq!(astring[2..=3].as_bytes(), b"23");
+        assert_eq!(astring[..=0].as_bytes(), b"0");
+        assert_eq!(astring[..].len(), 10);
+        assert!(astring[10..].is_empty());
+        let astr = astring.as_ascii_str();
+        assert_eq!(&astr[3..4], &astring[3..4]);
+
+        astring[..3].as_bytes_mut().fill(b'x');
+        assert_eq!(astring.to_string(), "xxx3456789");
+        astring[3..=4][0] = b'y';
+        assert_eq!(astring.to_string(), "xxxy456789");
+    }
+
+    #[test]
+    fn test_get_range() {
+        let astring = AsciiString::try_from("abcdef").unwrap();
+        assert_eq!(astring.get(1..3).unwrap().as_bytes(), b"bc");
+        assert_eq!(astring.get(6..).unwrap().len(), 0);
+        assert!(astring.get(7..).is_none());
+        assert!(astring.get(..=6).is_none());
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = astring.get(4..2);
+        assert!(reversed.is_none());
+        assert_eq!(astring.substring(2..4).to_string(), "cd");
+        assert_eq!(astring.substring(..), astring);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_range_out_of_bounds() {
+        let astring = AsciiString::try_from("abc").unwrap();
+        let _ = &astring[2..4];
+    }
+
+    #[test]
+    fn test_as_str_to_string() {
+        let astring = AsciiString::try_from("plain ascii").unwrap();
+        assert_eq!(astring.as_str(), Some("plain ascii"));
+        assert_eq!(String::from(astring), "plain ascii");
+
+        let text = "é at start, mid ÿ and end ñ";
+        let astring = AsciiString::try_from(text).unwrap();
+        assert_eq!(astring.as_str(), None);
+        assert_eq!(astring[1..16].as_str(), Some(" at start, mid "));
+        assert_eq!(astring.to_string(), text);
+        assert_eq!(format!("{astring:?}"), text);
+        assert_eq!(String::from(&astring), text);
+        assert_eq!(String::from(astring), text);
+
+        let all: Vec<u8> = (0..=255).collect();
+        let expected: String = all.iter().map(|byte| *byte as char).collect();
+        assert_eq!(AsciiStr::from_bytes(&all).to_string(), expected);
+        let string = String::from(AsciiStr::from_bytes(&all));
+        assert_eq!(string, expected);
+        // sized up front, Extended bytes included.
+        assert_eq!(string.capacity(), expected.len());
+    }
+}
@@ -1,7 +1,9 @@
-pub use crate::ascii_common::ReadLineResult;
+use crate::ascii_common::{line_too_long, LineScanner, DEFAULT_BUF_SIZE};
+pub use crate::ascii_common::{LineEnding, LineTerminator, ReadLineResult};
 use crate::ascii_consts::*;
+use crate::ascii_pattern::memchr;
 use crate::ascii_string::AsciiString;
-use std::io::{BufRead, BufReader, BufWriter, IntoInnerError, Read, Write};
+use std::io::{BufRead, BufReader, BufWriter, ErrorKind, IntoInnerError, Read, Write};
 
 /// A buffered reader which reads data as ascii characters.
 /// # Sample Usage
@@ -62,87 +64,308 @@ use std::io::{BufRead, BufReader, BufWriter, IntoInnerError, Read, Write};
 #[derive(Debug)]
 pub struct AsciiStreamReader<R> {
     inner: BufReader<R>,
+    scanner: LineScanner,
 }
 
 impl<R: Read> AsciiStreamReader<R> {
     /// Creates a new AsciiStreamReader with a default 8KB buffer capacity.
     pub fn new(inner: R) -> Self {
-        Self {
-            inner: BufReader::new(inner),
-        }
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner)
     }
     /// Creates a new AsciiStreamReader with the specified buffer capacity.
     pub fn with_capacity(capacity: usize, inner: R) -> Self {
         Self {
             inner: BufReader::with_capacity(capacity, inner),
+            scanner: LineScanner::default(),
         }
     }
     /// Returns the number of bytes the internal buffer can hold.
     pub fn capacity(&self) -> usize {
         self.inner.capacity()
     }
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        self.inner.get_ref()
+    }
+    /// Returns a mutable reference to the underlying reader.
+    /// * reading from it directly skips the bytes in the internal buffer.
+    pub fn get_mut(&mut self) -> &mut R {
+        self.inner.get_mut()
+    }
+    /// Returns the bytes in the internal buffer, which have been read from the underlying reader but not consumed yet.
+    pub fn buffer(&self) -> &[u8] {
+        self.inner.buffer()
+    }
+    /// Unwraps this AsciiStreamReader, returning the underlying reader.
+    /// * the bytes in the internal buffer are lost.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+    /// Returns the line terminator(s) read_line() splits on.
+    pub fn line_ending(&self) -> LineEnding {
+        self.scanner.ending
+    }
+    /// Sets the line terminator(s) read_line() splits on (the default is `LineEnding::Lf`, LF or CR LF).
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.scanner.ending = line_ending;
+    }
+    /// Returns true if read_line() keeps the line terminator in the buffer.
+    pub fn keep_terminator(&self) -> bool {
+        self.scanner.keep_terminator
+    }
+    /// Sets whether read_line() keeps the line terminator in the buffer (the default is false).
+    pub fn set_keep_terminator(&mut self, keep_terminator: bool) {
+        self.scanner.keep_terminator = keep_terminator;
+    }
+    /// Returns the maximum line length, if any.
+    pub fn max_line_len(&self) -> Option<usize> {
+        self.scanner.max_line_len
+    }
+    /// Sets the maximum line length, in bytes, not counting the line terminator (the default is None, no limit).
+    /// <br>
+    /// Bounds the memory a line can take, e.g. when reading from an untrusted client.
+    /// * read_line() stops at max_line_len bytes and returns `ReadLineResult::TooLong`,
+    ///   with the first max_line_len bytes in the buffer. The next read_line() skips the rest of the line.
+    /// * read_until() returns an `InvalidData` error once more than max_line_len bytes are read without finding the byte.
+    /// * lines() and lines_buffered() return an `InvalidData` error for a line that is too long, then continue with the next line.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// # use std::io::Cursor;
+    /// let mut reader = AsciiStreamReader::new(Cursor::new(b"short\nthis line is too long\nok"));
+    /// reader.set_max_line_len(Some(8));
+    /// let mut line = AsciiString::new();
+    ///
+    /// assert!(reader.read_line(&mut line).is_success());
+    /// assert_eq!(line.to_string(), "short");
+    /// assert!(reader.read_line(&mut line).is_too_long());
+    /// assert_eq!(line.to_string(), "this lin");
+    /// assert!(reader.read_line(&mut line).is_success());
+    /// assert_eq!(line.to_string(), "ok");
+    /// ```
+    pub fn set_max_line_len(&mut self, max_line_len: Option<usize>) {
+        self.scanner.max_line_len = max_line_len;
+    }
+    /// Returns the terminator that ended the last line read by read_line().
+    /// * None if the last line ended at EOF without a terminator (or no line has been read).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// # use std::io::Cursor;
+    /// let mut reader = AsciiStreamReader::new(Cursor::new(b"one\r\ntwo\rthree"));
+    /// reader.set_line_ending(LineEnding::Any);
+    /// reader.set_keep_terminator(true);
+    /// let mut line = AsciiString::new();
+    ///
+    /// reader.read_line(&mut line);
+    /// assert_eq!(line.to_string(), "one\r\n");
+    /// assert_eq!(reader.last_terminator(), Some(LineTerminator::CrLf));
+    /// reader.read_line(&mut line);
+    /// assert_eq!(line.to_string(), "two\r");
+    /// assert_eq!(reader.last_terminator(), Some(LineTerminator::Cr));
+    /// reader.read_line(&mut line);
+    /// assert_eq!(line.to_string(), "three");
+    /// assert_eq!(reader.last_terminator(), None);
+    /// ```
+    pub fn last_terminator(&self) -> Option<LineTerminator> {
+        self.scanner.terminator
+    }
     /// Reads the next line of ascii characters into the specified AsciiString.
-    /// * the line terminator is discarded.
+    /// * lines end as set by set_line_ending() (LF or CR LF by default).
+    /// * the line terminator is discarded, unless set_keep_terminator(true) was called.
     /// * the returned Success(value) is the number of bytes pushed to AsciiString, not the number of bytes read
     ///   * meaning that if the line terminator is \r\n, the returned value is 2 less than the number of bytes read.
     ///   * returned value of 0 does not mean EOF. It means that the line is empty, but EOF has not been reached.
+    /// * lines longer than max_line_len (if set) return TooLong (see set_max_line_len()).
     #[inline]
     pub fn read_line(&mut self, buf: &mut AsciiString) -> ReadLineResult {
-        let result = self.read_until(LF, buf);
-        match result {
-            Ok(result) => {
-                if result > 0 {
-                    if buf[result - 1] == LF {
-                        buf.pop();
-                        if result > 1 && buf[result - 2] == CR {
-                            buf.pop();
-                        }
-                    }
-                    ReadLineResult::Success(buf.len())
-                } else {
-                    ReadLineResult::EOF
-                }
+        self.scanner.start(buf);
+        loop {
+            let available = match self.inner.fill_buf() {
+                Ok(available) => available,
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => return self.scanner.error(err),
+            };
+            let (used, result) = self.scanner.feed(available, buf);
+            self.inner.consume(used);
+            if let Some(result) = result {
+                return result;
             }
-            Err(err) => ReadLineResult::Error(err),
         }
     }
     /// Reads until the specified byte is encountered, or EOF is reached, into the specified AsciiString.
     /// * the specified byte is included in the AsciiString.
+    /// * the AsciiString is filled in place, straight from the internal buffer, so its capacity is reused
+    ///   (no allocations once it is large enough for the longest line).
+    /// * on error, the AsciiString holds the bytes read before the error.
+    /// * returns an `InvalidData` error once more than max_line_len (if set) bytes are read without finding the byte.
     #[inline]
     pub fn read_until(&mut self, byte: u8, buf: &mut AsciiString) -> std::io::Result<usize> {
         buf.clear();
-        let mut vec = Vec::new();
-        let result = self.inner.read_until(byte, &mut vec);
-        if result.is_ok() {
-            *buf = AsciiString::from(vec);
+        let max_line_len = self.scanner.max_line_len;
+        let mut read = 0;
+        loop {
+            let available = match self.inner.fill_buf() {
+                Ok(available) => available,
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            // never read more than one byte past max_line_len.
+            let room = max_line_len.map_or(usize::MAX, |max| max.saturating_add(1) - buf.len());
+            let chunk = &available[..available.len().min(room)];
+            let (done, used) = match memchr(byte, chunk) {
+                Some(inx) => {
+                    buf.push_bytes(&chunk[..=inx]);
+                    (true, inx + 1)
+                }
+                None => {
+                    buf.push_bytes(chunk);
+                    (available.is_empty(), chunk.len())
+                }
+            };
+            self.inner.consume(used);
+            read += used;
+            if done {
+                return Ok(read);
+            }
+            if let Some(max_line_len) = max_line_len.filter(|&max| buf.len() > max) {
+                return Err(line_too_long(max_line_len));
+            }
         }
-        result
     }
     /// Reads until EOF is reached, into the specified AsciiString.
+    /// * the AsciiString's capacity is reused.
     pub fn read_to_end(&mut self, buf: &mut AsciiString) -> std::io::Result<usize> {
         buf.clear();
-        let mut vec = Vec::new();
-        let result = self.inner.read_to_end(&mut vec);
-        if result.is_ok() {
-            *buf = AsciiString::from(vec);
-        }
-        result
+        self.inner.read_to_end(buf.as_mut_vec())
     }
     /// Reads the specified number of bytes into the specified AsciiString.
+    /// * the AsciiString's capacity is reused.
     pub fn read_bytes(&mut self, buf: &mut AsciiString, len: usize) -> std::io::Result<usize> {
-        buf.clear();
-        let mut vec = vec![0; len];
-        let result = self.inner.read(&mut vec);
-        if let Ok(result) = result {
-            vec.truncate(result);
-            *buf = AsciiString::from(vec);
-            Ok(result)
-        } else {
-            result
+        let vec = buf.as_mut_vec();
+        vec.clear();
+        vec.resize(len, 0);
+        let result = self.inner.read(vec);
+        vec.truncate(*result.as_ref().unwrap_or(&0));
+        result
+    }
+    /// Returns an iterator over the lines of the stream, as `std::io::Result<AsciiString>`s.
+    /// * line terminators are discarded, the same as read_line().
+    /// * unlike a `while read_line().is_success()` loop, errors are returned rather than ending the loop.
+    /// * each line is a new AsciiString; use lines_buffered() to reuse a single buffer instead.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// # use std::io::Cursor;
+    /// let reader = AsciiStreamReader::new(Cursor::new(b"one\r\ntwo\nthree"));
+    /// let lines: Vec<String> = reader
+    ///     .lines()
+    ///     .map(|line| line.unwrap().to_string())
+    ///     .collect();
+    /// assert_eq!(lines, ["one", "two", "three"]);
+    /// ```
+    pub fn lines(self) -> AsciiLines<R> {
+        AsciiLines { reader: self }
+    }
+    /// Returns a lending line reader, which reads each line into the same buffer (see `AsciiLinesBuffered`).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// # use std::io::Cursor;
+    /// let mut reader = AsciiStreamReader::new(Cursor::new(b"one\ntwo\nthree"));
+    /// let mut lines = reader.lines_buffered();
+    /// let mut longest = 0;
+    /// while let Some(line) = lines.next_line() {
+    ///     longest = longest.max(line.unwrap().len());
+    /// }
+    /// assert_eq!(longest, 5);
+    /// ```
+    pub fn lines_buffered(&mut self) -> AsciiLinesBuffered<'_, R> {
+        AsciiLinesBuffered {
+            reader: self,
+            line: AsciiString::new(),
         }
     }
 }
 
+/// Reads raw bytes through the internal buffer, so an `AsciiStreamReader` can be used
+/// wherever a `Read` is expected (`io::copy`, `Take`, decompressors, ...).
+/// <br>
+/// The inherent read_line(), read_until() and read_to_end() take precedence over the trait methods;
+/// call them as `Read::read_to_end(&mut reader, &mut vec)` to use the trait versions.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// use std::io::{Cursor, Read};
+///
+/// let mut reader = AsciiStreamReader::new(Cursor::new(b"header\nbody"));
+/// let mut line = AsciiString::new();
+/// reader.read_line(&mut line);
+///
+/// let mut body = String::new();
+/// reader.by_ref().take(2).read_to_string(&mut body).unwrap();
+/// assert_eq!(body, "bo");
+/// ```
+impl<R: Read> Read for AsciiStreamReader<R> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl<R: Read> BufRead for AsciiStreamReader<R> {
+    #[inline]
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt)
+    }
+}
+
+/// An iterator over the lines of an `AsciiStreamReader` (see `AsciiStreamReader::lines`).
+#[derive(Debug)]
+pub struct AsciiLines<R> {
+    reader: AsciiStreamReader<R>,
+}
+
+impl<R> AsciiLines<R> {
+    /// Unwraps this iterator, returning the underlying reader.
+    pub fn into_inner(self) -> AsciiStreamReader<R> {
+        self.reader
+    }
+}
+
+impl<R: Read> Iterator for AsciiLines<R> {
+    type Item = std::io::Result<AsciiString>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut line = AsciiString::new();
+        self.reader.read_line(&mut line).into_item(|| line)
+    }
+}
+
+/// A lending line reader over an `AsciiStreamReader` (see `AsciiStreamReader::lines_buffered`).
+/// <br>
+/// Every line is read into the same buffer, so no allocations are needed once it is large enough
+/// for the longest line. The line borrows the reader, so it can't be an `Iterator`; use
+/// `while let Some(line) = lines.next_line()` instead.
+#[derive(Debug)]
+pub struct AsciiLinesBuffered<'a, R> {
+    reader: &'a mut AsciiStreamReader<R>,
+    line: AsciiString,
+}
+
+impl<R: Read> AsciiLinesBuffered<'_, R> {
+    /// Reads the next line, or returns `None` at EOF.
+    /// * line terminators are discarded, the same as read_line().
+    pub fn next_line(&mut self) -> Option<std::io::Result<&AsciiString>> {
+        let line = &mut self.line;
+        self.reader.read_line(line).into_item(|| &*line)
+    }
+}
+
 /// A buffered writer that writes ascii characters to an underlying stream.
 /// # Sample Usage
 /// ```
@@ -178,44 +401,65 @@ impl<R: Read> AsciiStreamReader<R> {
 #[derive(Debug)]
 pub struct AsciiStreamWriter<W: Write> {
     inner: BufWriter<W>,
+    line_ending: LineEnding,
 }
 
 impl<W: Write> AsciiStreamWriter<W> {
     /// Creates a new AsciiStreamWriter with a default 8KB buffer capacity.
     pub fn new(inner: W) -> Self {
-        Self {
-            inner: BufWriter::new(inner),
-        }
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner)
     }
     /// Creates a new AsciiStreamWriter with the specified buffer capacity.
     pub fn with_capacity(capacity: usize, inner: W) -> Self {
         Self {
             inner: BufWriter::with_capacity(capacity, inner),
+            line_ending: LineEnding::default(),
         }
     }
     /// Returns the number of bytes the internal buffer can hold.
     pub fn capacity(&self) -> usize {
         self.inner.capacity()
     }
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        self.inner.get_ref()
+    }
+    /// Returns a mutable reference to the underlying writer.
+    /// * writing to it directly skips the bytes in the internal buffer, which are written later.
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner.get_mut()
+    }
+    /// Returns the bytes in the internal buffer, which have not been written to the underlying writer yet.
+    pub fn buffer(&self) -> &[u8] {
+        self.inner.buffer()
+    }
+    /// Returns the line terminator write_line() writes.
+    pub fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+    /// Sets the line terminator write_line() writes (the default is `LineEnding::Lf`).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut writer = AsciiStreamWriter::new(Vec::new());
+    /// writer.set_line_ending(LineEnding::custom(&[RS]));
+    /// writer.write_line(&AsciiString::try_from("one").unwrap()).unwrap();
+    /// writer.write_line(&AsciiString::try_from("two").unwrap()).unwrap();
+    /// assert_eq!(writer.into_inner().unwrap(), b"one\x1etwo\x1e");
+    /// ```
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
     /// Writes the entire AsciiString to the stream.    
     #[inline]
     pub fn write(&mut self, buf: &AsciiString) -> std::io::Result<()> {
-        //self.inner.write_all(buf.as_bytes())
-        let (a, b) = buf.bytes.as_slices();
-        let mut result = Ok(());
-        if !a.is_empty() {
-            result = self.inner.write_all(a);
-        }
-        if result.is_ok() && !b.is_empty() {
-            result = self.inner.write_all(b);
-        }
-        result
+        self.inner.write_all(buf.as_bytes())
     }
-    /// Writes the entire AsciiString to the stream, followed by a newline.   
+    /// Writes the entire AsciiString to the stream, followed by the line terminator (a newline by default, see set_line_ending()).
     #[inline]
     pub fn write_line(&mut self, buf: &AsciiString) -> std::io::Result<()> {
         self.write(buf)?;
-        self.inner.write_all(&[LF])
+        self.inner.write_all(self.line_ending.as_bytes())
     }
     /// Writes the entire AsciiString to the stream, followed by a carriage return and a newline.    
     #[inline]
@@ -234,9 +478,39 @@ impl<W: Write> AsciiStreamWriter<W> {
     }
 }
 
+/// Writes raw bytes through the internal buffer, so an `AsciiStreamWriter` can be used
+/// wherever a `Write` is expected (`io::copy`, `write!`, compressors, ...).
+/// <br>
+/// The inherent write() (of an AsciiString) and flush() take precedence over the trait methods;
+/// call them as `Write::write(&mut writer, bytes)` to use the trait versions.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// use std::io::{Cursor, Write};
+///
+/// let mut writer = AsciiStreamWriter::new(Vec::new());
+/// write!(writer, "{} + {}", 1, 2).unwrap();
+/// std::io::copy(&mut Cursor::new(b" = 3"), &mut writer).unwrap();
+/// assert_eq!(writer.into_inner().unwrap(), b"1 + 2 = 3");
+/// ```
+impl<W: Write> Write for AsciiStreamWriter<W> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.inner.write(buf)
+    }
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.inner.write_all(buf)
+    }
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use std::io::{Cursor, Write};
+    use std::io::Cursor;
 
     #[test]
     fn test_ascii_stream_reader() {
@@ -258,6 +532,264 @@ mod test {
     }
 
     #[test]
+    fn test_ascii_stream_reader_reuses_buffer() {
+        use super::*;
+
+        // a tiny internal buffer, so lines span several fill_buf calls.
+        let mut stream = AsciiStreamReader::with_capacity(
+            4,
+            Cursor::new(b"a much longer first line\nshort\n\nlast"),
+        );
+        let mut buf = AsciiString::with_capacity(64);
+        let capacity = buf.capacity();
+        assert_eq!(stream.read_until(LF, &mut buf).unwrap(), 25);
+        assert_eq!(buf.to_string(), "a much longer first line\n");
+        assert_eq!(stream.read_line(&mut buf).unwrap(), 5);
+        assert_eq!(buf.to_string(), "short");
+        assert_eq!(stream.read_line(&mut buf).unwrap(), 0);
+        assert_eq!(stream.read_line(&mut buf).unwrap(), 4);
+        assert_eq!(buf.to_string(), "last");
+        assert!(stream.read_line(&mut buf).is_eof());
+        assert_eq!(buf.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_ascii_stream_lines() {
+        use super::*;
+
+        let data = b"one\r\n\ntwo\nthree\n";
+        let lines: Vec<String> = AsciiStreamReader::new(Cursor::new(data))
+            .lines()
+            .map(|line| line.unwrap().to_string())
+            .collect();
+        assert_eq!(lines, ["one", "", "two", "three"]);
+
+        let mut reader = AsciiStreamReader::with_capacity(2, Cursor::new(data));
+        let mut lines = reader.lines_buffered();
+        let mut buffered = Vec::new();
+        while let Some(line) = lines.next_line() {
+            buffered.push(line.unwrap().to_string());
+        }
+        assert_eq!(buffered, ["one", "", "two", "three"]);
+    }
+
+    #[test]
+    fn test_ascii_stream_line_endings() {
+        use super::*;
+
+        fn read_all(
+            data: &[u8],
+            ending: LineEnding,
+            keep: bool,
+            capacity: usize,
+        ) -> Vec<(String, Option<LineTerminator>)> {
+            let mut reader = AsciiStreamReader::with_capacity(capacity, Cursor::new(data));
+            reader.set_line_ending(ending);
+            reader.set_keep_terminator(keep);
+            let mut line = AsciiString::new();
+            let mut lines = Vec::new();
+            while reader.read_line(&mut line).is_success() {
+                lines.push((line.to_string(), reader.last_terminator()));
+            }
+            lines
+        }
+
+        let data = b"a\nb\r\nc\rd\r\re\x85f\x1e";
+        // capacity 1 splits every CR LF across reads.
+        for capacity in [1, 2, 3, 64] {
+            assert_eq!(
+                read_all(data, LineEnding::Lf, false, capacity),
+                [
+                    ("a".into(), Some(LineTerminator::Lf)),
+                    ("b".into(), Some(LineTerminator::CrLf)),
+                    ("c\rd\r\re\u{85}f\u{1e}".into(), None),
+                ]
+            );
+            assert_eq!(
+                read_all(data, LineEnding::CrLf, false, capacity),
+                [
+                    ("a\nb".into(), Some(LineTerminator::CrLf)),
+                    ("c\rd\r\re\u{85}f\u{1e}".into(), None),
+                ]
+            );
+            assert_eq!(
+                read_all(data, LineEnding::Cr, true, capacity),
+                [
+                    ("a\nb\r".into(), Some(LineTerminator::Cr)),
+                    ("\nc\r".into(), Some(LineTerminator::Cr)),
+                    ("d\r".into(), Some(LineTerminator::Cr)),
+                    ("\r".into(), Some(LineTerminator::Cr)),
+                    ("e\u{85}f\u{1e}".into(), None),
+                ]
+            );
+            assert_eq!(
+                read_all(data, LineEnding::Any, false, capacity),
+                [
+                    ("a".into(), Some(LineTerminator::Lf)),
+                    ("b".into(), Some(LineTerminator::CrLf)),
+                    ("c".into(), Some(LineTerminator::Cr)),
+                    ("d".into(), Some(LineTerminator::Cr)),
+                    ("".into(), Some(LineTerminator::Cr)),
+                    ("e\u{85}f\u{1e}".into(), None),
+                ]
+            );
+            assert_eq!(
+                read_all(data, LineEnding::custom(&[EXT_133, RS]), true, capacity),
+                [
+                    (
+                        "a\nb\r\nc\rd\r\re\u{85}".into(),
+                        Some(LineTerminator::Byte(EXT_133))
+                    ),
+                    ("f\u{1e}".into(), Some(LineTerminator::Byte(RS))),
+                ]
+            );
+        }
+        // a CR at EOF is still a terminator.
+        assert_eq!(
+            read_all(b"a\r", LineEnding::Any, true, 1),
+            [("a\r".into(), Some(LineTerminator::Cr))]
+        );
+    }
+
+    #[test]
+    fn test_ascii_stream_max_line_len() {
+        use super::*;
+
+        fn read_all(data: &[u8], ending: LineEnding, capacity: usize) -> Vec<String> {
+            let mut reader = AsciiStreamReader::with_capacity(capacity, Cursor::new(data));
+            reader.set_line_ending(ending);
+            reader.set_max_line_len(Some(4));
+            let mut line = AsciiString::new();
+            let mut lines = Vec::new();
+            loop {
+                match reader.read_line(&mut line) {
+                    ReadLineResult::Success(_) => lines.push(line.to_string()),
+                    ReadLineResult::TooLong(len) => {
+                        assert_eq!(len, line.len());
+                        lines.push(format!("{line}..."))
+                    }
+                    ReadLineResult::EOF => return lines,
+                    ReadLineResult::Error(err) => panic!("{err}"),
+                }
+            }
+        }
+
+        for capacity in [1, 2, 3, 64] {
+            // a line of exactly max_line_len fits, even with a CR LF terminator.
+            assert_eq!(
+                read_all(
+                    b"abcd\r\nabcde\nab\nabcdefghij\r\nabcd",
+                    LineEnding::Lf,
+                    capacity
+                ),
+                ["abcd", "abcd...", "ab", "abcd...", "abcd"]
+            );
+            assert_eq!(
+                read_all(b"abcd\rabcde\r\r\nabcdefghij", LineEnding::Any, capacity),
+                ["abcd", "abcd...", "", "abcd..."]
+            );
+            // the skipped rest of a CrLf line keeps lone LFs and CRs.
+            assert_eq!(
+                read_all(b"abcdef\n\r\r\nab\r\n", LineEnding::CrLf, capacity),
+                ["abcd...", "ab"]
+            );
+        }
+
+        let data = b"abcd\nabcde\nab";
+        let mut reader = AsciiStreamReader::with_capacity(2, Cursor::new(data));
+        reader.set_max_line_len(Some(4));
+        let lines: Vec<_> = reader
+            .lines()
+            .map(|line| line.map(|l| l.to_string()))
+            .collect();
+        assert_eq!(lines[0].as_ref().unwrap(), "abcd");
+        assert_eq!(
+            lines[1].as_ref().unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        assert_eq!(lines[2].as_ref().unwrap(), "ab");
+        assert_eq!(lines.len(), 3);
+
+        let mut reader = AsciiStreamReader::with_capacity(2, Cursor::new(data));
+        reader.set_max_line_len(Some(4));
+        let mut buf = AsciiString::new();
+        assert_eq!(reader.read_until(LF, &mut buf).unwrap(), 5);
+        let err = reader.read_until(LF, &mut buf).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(buf.to_string(), "abcde");
+    }
+
+    #[test]
+    fn test_ascii_stream_io_traits() {
+        use super::*;
+
+        let data = b"header\r\nline 1\nline 2";
+        let mut reader = AsciiStreamReader::with_capacity(4, Cursor::new(data));
+        let mut line = AsciiString::new();
+        assert!(reader.read_line(&mut line).is_success());
+        assert_eq!(reader.buffer(), b"");
+        assert_eq!(reader.get_ref().position(), 8);
+
+        // the trait methods, through a generic BufRead.
+        fn first_line(mut reader: impl BufRead) -> String {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            line
+        }
+        assert_eq!(first_line(&mut reader), "line 1\n");
+        assert_eq!(reader.buffer(), b"l");
+
+        let mut writer = AsciiStreamWriter::with_capacity(4, Vec::new());
+        assert_eq!(std::io::copy(&mut reader, &mut writer).unwrap(), 6);
+        assert_eq!(writer.buffer(), b"");
+        assert_eq!(writer.get_ref(), b"line 2");
+        Write::write_all(&mut writer, b"!").unwrap();
+        assert_eq!(writer.buffer(), b"!");
+        assert_eq!(writer.into_inner().unwrap(), b"line 2!");
+        assert_eq!(reader.into_inner().position(), data.len() as u64);
+    }
+
+    #[test]
+    fn test_ascii_stream_writer_line_ending() {
+        use super::*;
+
+        let line = AsciiString::try_from("a").unwrap();
+        for (ending, expected) in [
+            (LineEnding::Lf, &b"a\n"[..]),
+            (LineEnding::CrLf, b"a\r\n"),
+            (LineEnding::Cr, b"a\r"),
+            (LineEnding::Any, b"a\n"),
+            (LineEnding::custom(&[RS, LF]), b"a\x1e"),
+        ] {
+            let mut writer = AsciiStreamWriter::new(Vec::new());
+            writer.set_line_ending(ending);
+            writer.write_line(&line).unwrap();
+            assert_eq!(writer.into_inner().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_ascii_stream_lines_error() {
+        use super::*;
+
+        // fails after the first read.
+        struct Failing(bool);
+        impl Read for Failing {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                if std::mem::replace(&mut self.0, true) {
+                    return Err(std::io::Error::other("broken"));
+                }
+                buf[..4].copy_from_slice(b"ok\nx");
+                Ok(4)
+            }
+        }
+        let mut lines = AsciiStreamReader::new(Failing(false)).lines();
+        assert_eq!(lines.next().unwrap().unwrap().to_string(), "ok");
+        assert!(lines.next().unwrap().is_err());
+    }
+
+    #[test]
+    #[allow(unused_must_use)]
     fn test_ascii_stream_reader_bytes() {
         use super::*;
 
@@ -1,10 +1,17 @@
 #![cfg(feature = "async")]
 
-pub use crate::ascii_common::ReadLineResult;
+use crate::ascii_common::{line_too_long, LineScanner, DEFAULT_BUF_SIZE};
+pub use crate::ascii_common::{LineEnding, LineTerminator, ReadLineResult};
 use crate::ascii_consts::*;
+use crate::ascii_pattern::memchr;
 use crate::ascii_string::AsciiString;
-use futures::io::{AsyncRead, AsyncWrite, BufReader, BufWriter};
-use futures::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt};
+use futures::future::poll_fn;
+use futures::io::{AsyncBufRead, AsyncRead, AsyncWrite, BufReader, BufWriter};
+use futures::{ready, Stream};
+use futures::{AsyncReadExt, AsyncWriteExt};
+use std::io::ErrorKind;
+use std::pin::Pin;
+use std::task::{Context, Poll};
 
 /// An asynchronous buffered reader which reads data as ascii characters.
 /// # Examples
@@ -42,83 +49,363 @@ use futures::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt};
 #[derive(Debug)]
 pub struct AsciiStreamReaderAsync<R> {
     inner: BufReader<R>,
+    scanner: LineScanner,
 }
 
 impl<R: AsyncRead + Unpin> AsciiStreamReaderAsync<R> {
     /// Creates a new `AsciiStreamReaderAsync` with a default buffer capacity.
     pub fn new(inner: R) -> Self {
-        Self {
-            inner: BufReader::new(inner),
-        }
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner)
     }
     /// Creates a new `AsciiStreamReaderAsync` with the specified buffer capacity.
     pub fn with_capacity(capacity: usize, inner: R) -> Self {
         Self {
             inner: BufReader::with_capacity(capacity, inner),
+            scanner: LineScanner::default(),
         }
     }
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        self.inner.get_ref()
+    }
+    /// Returns a mutable reference to the underlying reader.
+    /// * reading from it directly skips the bytes in the internal buffer.
+    pub fn get_mut(&mut self) -> &mut R {
+        self.inner.get_mut()
+    }
+    /// Returns the bytes in the internal buffer, which have been read from the underlying reader but not consumed yet.
+    pub fn buffer(&self) -> &[u8] {
+        self.inner.buffer()
+    }
+    /// Consumes self, returning the underlying reader.
+    /// * the bytes in the internal buffer are lost.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+    /// Returns the line terminator(s) read_line() splits on.
+    pub fn line_ending(&self) -> LineEnding {
+        self.scanner.ending
+    }
+    /// Sets the line terminator(s) read_line() splits on (the default is `LineEnding::Lf`, LF or CR LF).
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.scanner.ending = line_ending;
+    }
+    /// Returns true if read_line() keeps the line terminator in the buffer.
+    pub fn keep_terminator(&self) -> bool {
+        self.scanner.keep_terminator
+    }
+    /// Sets whether read_line() keeps the line terminator in the buffer (the default is false).
+    pub fn set_keep_terminator(&mut self, keep_terminator: bool) {
+        self.scanner.keep_terminator = keep_terminator;
+    }
+    /// Returns the maximum line length, if any.
+    pub fn max_line_len(&self) -> Option<usize> {
+        self.scanner.max_line_len
+    }
+    /// Sets the maximum line length, in bytes, not counting the line terminator (the default is None, no limit).
+    /// <br>
+    /// Bounds the memory a line can take, e.g. when reading from an untrusted client.
+    /// * read_line() stops at max_line_len bytes and returns `ReadLineResult::TooLong`,
+    ///   with the first max_line_len bytes in the buffer. The next read_line() skips the rest of the line.
+    /// * read_until() returns an `InvalidData` error once more than max_line_len bytes are read without finding the byte.
+    /// * lines() and lines_buffered() return an `InvalidData` error for a line that is too long, then continue with the next line.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// use futures::io::Cursor;
+    /// use futures::StreamExt;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let mut reader = AsciiStreamReaderAsync::new(Cursor::new(b"short\nthis line is too long\nok"));
+    /// reader.set_max_line_len(Some(8));
+    /// let lines: Vec<_> = reader.lines().collect().await;
+    /// assert_eq!(lines[0].as_ref().unwrap().to_string(), "short");
+    /// assert_eq!(lines[1].as_ref().unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    /// assert_eq!(lines[2].as_ref().unwrap().to_string(), "ok");
+    /// # });
+    /// ```
+    pub fn set_max_line_len(&mut self, max_line_len: Option<usize>) {
+        self.scanner.max_line_len = max_line_len;
+    }
+    /// Returns the terminator that ended the last line read by read_line().
+    /// * None if the last line ended at EOF without a terminator (or no line has been read).
+    pub fn last_terminator(&self) -> Option<LineTerminator> {
+        self.scanner.terminator
+    }
     /// Reads a line from the stream into the specified buffer, removing the line ending.
+    /// * lines end as set by set_line_ending() (LF or CR LF by default).
+    /// * the line ending is kept if set_keep_terminator(true) was called.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// use futures::io::Cursor;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let mut reader = AsciiStreamReaderAsync::new(Cursor::new(b"one\rtwo\r\nthree"));
+    /// reader.set_line_ending(LineEnding::Any);
+    /// let mut line = AsciiString::new();
+    /// let mut terminators = Vec::new();
+    /// while reader.read_line(&mut line).await.is_success() {
+    ///     terminators.push(reader.last_terminator());
+    /// }
+    /// assert_eq!(
+    ///     terminators,
+    ///     [Some(LineTerminator::Cr), Some(LineTerminator::CrLf), None]
+    /// );
+    /// # });
+    /// ```
     pub async fn read_line(&mut self, buf: &mut AsciiString) -> ReadLineResult {
-        let result = self.read_until(LF, buf).await;
-        match result {
-            Ok(result) => {
-                if result > 0 {
-                    if buf[result - 1] == LF {
-                        buf.pop();
-                        if result > 1 && buf[result - 2] == CR {
-                            buf.pop();
-                        }
-                    }
-                    ReadLineResult::Success(buf.len())
-                } else {
-                    ReadLineResult::EOF
-                }
-            }
-            Err(err) => ReadLineResult::Error(err),
-        }
+        self.scanner.start(buf);
+        poll_fn(|cx| poll_read_line(Pin::new(&mut self.inner), cx, &mut self.scanner, buf)).await
     }
     /// Reads data from the stream until the specified byte is encountered.
     /// * The specified byte is included in the returned data.
+    /// * the AsciiString is filled in place, straight from the internal buffer, so its capacity is reused
+    ///   (no allocations once it is large enough for the longest line).
+    /// * on error, the AsciiString holds the bytes read before the error.
     pub async fn read_until(&mut self, byte: u8, buf: &mut AsciiString) -> std::io::Result<usize> {
         buf.clear();
-        let mut vec = Vec::new();
-        let result = self.inner.read_until(byte, &mut vec).await;
-        if result.is_ok() {
-            *buf = AsciiString::from(vec);
-        }
-        result
+        let mut read = 0;
+        let max_line_len = self.scanner.max_line_len;
+        poll_fn(|cx| {
+            poll_read_until(
+                Pin::new(&mut self.inner),
+                cx,
+                byte,
+                max_line_len,
+                buf,
+                &mut read,
+            )
+        })
+        .await
     }
 
     /// Reads all data from the stream until EOF is encountered.
+    /// * the AsciiString's capacity is reused.
     pub async fn read_to_end(&mut self, buf: &mut AsciiString) -> std::io::Result<usize> {
         buf.clear();
-        let mut vec = Vec::new();
-        let result = self.inner.read_to_end(&mut vec).await;
-        if result.is_ok() {
-            *buf = AsciiString::from(vec);
-        }
-        result
+        self.inner.read_to_end(buf.as_mut_vec()).await
     }
     /// Reads the specified number of bytes from the stream.
+    /// * the AsciiString's capacity is reused.
     pub async fn read_bytes(
         &mut self,
         buf: &mut AsciiString,
         len: usize,
     ) -> std::io::Result<usize> {
-        buf.clear();
-        let mut vec = vec![0u8; len]; // Vec::with_capacity(len);
-                                      //vec.resize(len, 0);
-        let result = self.inner.read(&mut vec).await;
-        if let Ok(result) = result {
-            vec.truncate(result);
-            *buf = AsciiString::from(vec);
-            Ok(result)
-        } else {
-            result
+        let vec = buf.as_mut_vec();
+        vec.clear();
+        vec.resize(len, 0);
+        let result = self.inner.read(vec).await;
+        vec.truncate(*result.as_ref().unwrap_or(&0));
+        result
+    }
+    /// Returns a `Stream` over the lines of the stream, as `std::io::Result<AsciiString>`s.
+    /// * line terminators are discarded, the same as read_line().
+    /// * unlike a `while read_line().await.is_success()` loop, errors are returned rather than ending the loop.
+    /// * each line is a new AsciiString; use lines_buffered() to reuse a single buffer instead.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// use futures::io::Cursor;
+    /// use futures::StreamExt;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let reader = AsciiStreamReaderAsync::new(Cursor::new(b"one\r\ntwo\nthree"));
+    /// let lines: Vec<String> = reader
+    ///     .lines()
+    ///     .map(|line| line.unwrap().to_string())
+    ///     .collect()
+    ///     .await;
+    /// assert_eq!(lines, ["one", "two", "three"]);
+    /// # });
+    /// ```
+    pub fn lines(self) -> AsciiLinesAsync<R> {
+        AsciiLinesAsync {
+            reader: self,
+            line: AsciiString::new(),
+        }
+    }
+    /// Returns a lending line reader, which reads each line into the same buffer (see `AsciiLinesBufferedAsync`).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// use futures::io::Cursor;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let mut reader = AsciiStreamReaderAsync::new(Cursor::new(b"one\ntwo\nthree"));
+    /// let mut lines = reader.lines_buffered();
+    /// let mut longest = 0;
+    /// while let Some(line) = lines.next_line().await {
+    ///     longest = longest.max(line.unwrap().len());
+    /// }
+    /// assert_eq!(longest, 5);
+    /// # });
+    /// ```
+    pub fn lines_buffered(&mut self) -> AsciiLinesBufferedAsync<'_, R> {
+        AsciiLinesBufferedAsync {
+            reader: self,
+            line: AsciiString::new(),
         }
     }
 }
 
+/// Reads raw bytes through the internal buffer, so an `AsciiStreamReaderAsync` can be used
+/// wherever an `AsyncRead`/`AsyncBufRead` is expected (`futures::io::copy`, `take`, decompressors, ...).
+/// <br>
+/// The inherent read_line(), read_until(), read_to_end() and lines() take precedence over the
+/// `AsyncReadExt`/`AsyncBufReadExt` methods of the same name.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// use futures::io::{AsyncReadExt, Cursor};
+///
+/// # futures::executor::block_on(async {
+/// let mut reader = AsciiStreamReaderAsync::new(Cursor::new(b"header\nbody"));
+/// let mut line = AsciiString::new();
+/// reader.read_line(&mut line).await;
+///
+/// let mut body = Vec::new();
+/// futures::io::copy(&mut reader, &mut body).await.unwrap();
+/// assert_eq!(body, b"body");
+/// # });
+/// ```
+impl<R: AsyncRead + Unpin> AsyncRead for AsciiStreamReaderAsync<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_read(cx, buf)
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncBufRead for AsciiStreamReaderAsync<R> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<&[u8]>> {
+        Pin::new(&mut self.get_mut().inner).poll_fill_buf(cx)
+    }
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        Pin::new(&mut self.get_mut().inner).consume(amt)
+    }
+}
+
+/// Reads into `buf` until `byte` or EOF, straight out of the reader's buffer.
+/// <br>
+/// `read` keeps the number of bytes read so far between polls, and is reset when the read completes.
+fn poll_read_until<R: AsyncRead>(
+    mut reader: Pin<&mut BufReader<R>>,
+    cx: &mut Context<'_>,
+    byte: u8,
+    max_line_len: Option<usize>,
+    buf: &mut AsciiString,
+    read: &mut usize,
+) -> Poll<std::io::Result<usize>> {
+    loop {
+        let available = match ready!(reader.as_mut().poll_fill_buf(cx)) {
+            Ok(available) => available,
+            Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+            Err(err) => return Poll::Ready(Err(err)),
+        };
+        // never read more than one byte past max_line_len.
+        let room = max_line_len.map_or(usize::MAX, |max| max.saturating_add(1) - buf.len());
+        let chunk = &available[..available.len().min(room)];
+        let (done, used) = match memchr(byte, chunk) {
+            Some(inx) => {
+                buf.push_bytes(&chunk[..=inx]);
+                (true, inx + 1)
+            }
+            None => {
+                buf.push_bytes(chunk);
+                (available.is_empty(), chunk.len())
+            }
+        };
+        reader.as_mut().consume(used);
+        *read += used;
+        if done {
+            return Poll::Ready(Ok(std::mem::take(read)));
+        }
+        if let Some(max_line_len) = max_line_len.filter(|&max| buf.len() > max) {
+            *read = 0;
+            return Poll::Ready(Err(line_too_long(max_line_len)));
+        }
+    }
+}
+
+/// Reads the next line into `buf` straight out of the reader's buffer.
+/// <br>
+/// `scanner` keeps the line's settings and state between polls.
+fn poll_read_line<R: AsyncRead>(
+    mut reader: Pin<&mut BufReader<R>>,
+    cx: &mut Context<'_>,
+    scanner: &mut LineScanner,
+    buf: &mut AsciiString,
+) -> Poll<ReadLineResult> {
+    loop {
+        let available = match ready!(reader.as_mut().poll_fill_buf(cx)) {
+            Ok(available) => available,
+            Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+            Err(err) => return Poll::Ready(scanner.error(err)),
+        };
+        let (used, result) = scanner.feed(available, buf);
+        reader.as_mut().consume(used);
+        if let Some(result) = result {
+            return Poll::Ready(result);
+        }
+    }
+}
+
+/// A `Stream` over the lines of an `AsciiStreamReaderAsync` (see `AsciiStreamReaderAsync::lines`).
+#[derive(Debug)]
+pub struct AsciiLinesAsync<R> {
+    reader: AsciiStreamReaderAsync<R>,
+    line: AsciiString,
+}
+
+impl<R> AsciiLinesAsync<R> {
+    /// Unwraps this stream, returning the underlying reader.
+    pub fn into_inner(self) -> AsciiStreamReaderAsync<R> {
+        self.reader
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for AsciiLinesAsync<R> {
+    type Item = std::io::Result<AsciiString>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let reader = &mut this.reader;
+        let result = ready!(poll_read_line(
+            Pin::new(&mut reader.inner),
+            cx,
+            &mut reader.scanner,
+            &mut this.line,
+        ));
+        // the line is moved out, or dropped on error, so the next line starts empty.
+        let line = std::mem::take(&mut this.line);
+        Poll::Ready(result.into_item(|| line))
+    }
+}
+
+/// A lending line reader over an `AsciiStreamReaderAsync` (see `AsciiStreamReaderAsync::lines_buffered`).
+/// <br>
+/// Every line is read into the same buffer, so no allocations are needed once it is large enough
+/// for the longest line. The line borrows the reader, so it can't be a `Stream`; use
+/// `while let Some(line) = lines.next_line().await` instead.
+#[derive(Debug)]
+pub struct AsciiLinesBufferedAsync<'a, R> {
+    reader: &'a mut AsciiStreamReaderAsync<R>,
+    line: AsciiString,
+}
+
+impl<R: AsyncRead + Unpin> AsciiLinesBufferedAsync<'_, R> {
+    /// Reads the next line, or returns `None` at EOF.
+    /// * line terminators are discarded, the same as read_line().
+    pub async fn next_line(&mut self) -> Option<std::io::Result<&AsciiString>> {
+        let line = &mut self.line;
+        self.reader.read_line(line).await.into_item(|| &*line)
+    }
+}
+
 /// An asynchronous buffered writer which writes data as ascii characters.
 /// # Examples
 /// ```
@@ -150,41 +437,58 @@ impl<R: AsyncRead + Unpin> AsciiStreamReaderAsync<R> {
 #[derive(Debug)]
 pub struct AsciiStreamWriterAsync<W> {
     inner: BufWriter<W>,
+    line_ending: LineEnding,
 }
 
 impl<W: AsyncWrite + Unpin> AsciiStreamWriterAsync<W> {
     /// Creates a new `AsciiStreamWriterAsync` with a default buffer capacity.
     pub fn new(inner: W) -> Self {
-        Self {
-            inner: BufWriter::new(inner),
-        }
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner)
     }
 
     /// Creates a new `AsciiStreamWriterAsync` with the specified buffer capacity.
     pub fn with_capacity(capacity: usize, inner: W) -> Self {
         Self {
             inner: BufWriter::with_capacity(capacity, inner),
+            line_ending: LineEnding::default(),
         }
     }
 
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        self.inner.get_ref()
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    /// * writing to it directly skips the bytes in the internal buffer, which are written later.
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner.get_mut()
+    }
+
+    /// Returns the bytes in the internal buffer, which have not been written to the underlying writer yet.
+    pub fn buffer(&self) -> &[u8] {
+        self.inner.buffer()
+    }
+
+    /// Returns the line terminator write_line() writes.
+    pub fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    /// Sets the line terminator write_line() writes (the default is `LineEnding::Lf`).
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
     /// Writes the specified buffer to the stream.
     pub async fn write(&mut self, buf: &AsciiString) -> std::io::Result<()> {
-        //self.inner.write_all(buf.as_bytes())
-        let (a, b) = buf.bytes.as_slices();
-        let mut result = Ok(());
-        if !a.is_empty() {
-            result = self.inner.write_all(a).await;
-        }
-        if result.is_ok() && !b.is_empty() {
-            result = self.inner.write_all(b).await;
-        }
-        result
+        self.inner.write_all(buf.as_bytes()).await
     }
 
-    /// Writes the specified buffer to the stream, followed by a line feed.
+    /// Writes the specified buffer to the stream, followed by the line terminator (a line feed by default, see set_line_ending()).
     pub async fn write_line(&mut self, buf: &AsciiString) -> std::io::Result<()> {
         self.write(buf).await?;
-        self.inner.write_all(&[LF]).await
+        self.inner.write_all(self.line_ending.as_bytes()).await
     }
 
     /// Writes the specified buffer to the stream, followed by a carriage return and a line feed.
@@ -204,6 +508,39 @@ impl<W: AsyncWrite + Unpin> AsciiStreamWriterAsync<W> {
     }
 }
 
+/// Writes raw bytes through the internal buffer, so an `AsciiStreamWriterAsync` can be used
+/// wherever an `AsyncWrite` is expected (`futures::io::copy`, compressors, ...).
+/// <br>
+/// The inherent write() (of an AsciiString) and flush() take precedence over the `AsyncWriteExt` methods of the same name.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// use futures::io::{AsyncWriteExt, Cursor};
+///
+/// # futures::executor::block_on(async {
+/// let mut writer = AsciiStreamWriterAsync::new(Vec::new());
+/// writer.write_all(b"abc").await.unwrap();
+/// futures::io::copy(Cursor::new(b"def"), &mut writer).await.unwrap();
+/// writer.close().await.unwrap();
+/// assert_eq!(writer.into_inner(), b"abcdef");
+/// # });
+/// ```
+impl<W: AsyncWrite + Unpin> AsyncWrite for AsciiStreamWriterAsync<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_write(cx, buf)
+    }
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[tokio::test]
@@ -226,6 +563,164 @@ mod test {
         assert!(result.is_eof());
     }
 
+    #[tokio::test]
+    async fn test_read_line_reuses_buffer() {
+        use super::*;
+        use futures::io::Cursor;
+
+        // a tiny internal buffer, so lines span several fill_buf calls.
+        let mut stream =
+            AsciiStreamReaderAsync::with_capacity(4, Cursor::new(b"first line\r\n\nlast"));
+        let mut buf = AsciiString::with_capacity(64);
+        let capacity = buf.capacity();
+        assert_eq!(stream.read_line(&mut buf).await.unwrap(), 10);
+        assert_eq!(buf.to_string(), "first line");
+        assert_eq!(stream.read_line(&mut buf).await.unwrap(), 0);
+        assert_eq!(stream.read_line(&mut buf).await.unwrap(), 4);
+        assert_eq!(buf.to_string(), "last");
+        assert!(stream.read_line(&mut buf).await.is_eof());
+        assert_eq!(buf.capacity(), capacity);
+    }
+
+    #[tokio::test]
+    async fn test_lines_stream() {
+        use super::*;
+        use futures::io::Cursor;
+        use futures::StreamExt;
+
+        let data = b"one\r\n\ntwo\nthree\n";
+        let reader = AsciiStreamReaderAsync::with_capacity(2, Cursor::new(data));
+        let lines: Vec<String> = reader
+            .lines()
+            .map(|line| line.unwrap().to_string())
+            .collect()
+            .await;
+        assert_eq!(lines, ["one", "", "two", "three"]);
+
+        let mut reader = AsciiStreamReaderAsync::with_capacity(2, Cursor::new(data));
+        let mut lines = reader.lines_buffered();
+        let mut buffered = Vec::new();
+        while let Some(line) = lines.next_line().await {
+            buffered.push(line.unwrap().to_string());
+        }
+        assert_eq!(buffered, ["one", "", "two", "three"]);
+    }
+
+    #[tokio::test]
+    async fn test_read_line_endings() {
+        use super::*;
+        use futures::io::Cursor;
+        use futures::StreamExt;
+
+        let data = b"a\nb\r\nc\rd\x85";
+        let mut reader = AsciiStreamReaderAsync::with_capacity(1, Cursor::new(data));
+        reader.set_line_ending(LineEnding::Any);
+        reader.set_keep_terminator(true);
+        let mut line = AsciiString::new();
+        let mut lines = Vec::new();
+        while reader.read_line(&mut line).await.is_success() {
+            lines.push((line.to_string(), reader.last_terminator()));
+        }
+        assert_eq!(
+            lines,
+            [
+                ("a\n".into(), Some(LineTerminator::Lf)),
+                ("b\r\n".into(), Some(LineTerminator::CrLf)),
+                ("c\r".into(), Some(LineTerminator::Cr)),
+                ("d\u{85}".into(), None),
+            ]
+        );
+
+        let mut reader = AsciiStreamReaderAsync::with_capacity(1, Cursor::new(data));
+        reader.set_line_ending(LineEnding::custom(&[EXT_133, CR]));
+        let lines: Vec<String> = reader
+            .lines()
+            .map(|line| line.unwrap().to_string())
+            .collect()
+            .await;
+        assert_eq!(lines, ["a\nb", "\nc", "d"]);
+
+        let mut writer = AsciiStreamWriterAsync::new(Vec::new());
+        writer.set_line_ending(LineEnding::CrLf);
+        writer
+            .write_line(&AsciiString::try_from("a").unwrap())
+            .await
+            .unwrap();
+        writer.flush().await.unwrap();
+        assert_eq!(writer.into_inner(), b"a\r\n");
+    }
+
+    #[tokio::test]
+    async fn test_max_line_len() {
+        use super::*;
+        use futures::io::Cursor;
+
+        let data = b"abcd\r\nabcdefghij\nab";
+        let mut reader = AsciiStreamReaderAsync::with_capacity(3, Cursor::new(data));
+        reader.set_max_line_len(Some(4));
+        let mut line = AsciiString::new();
+        assert_eq!(reader.read_line(&mut line).await.unwrap(), 4);
+        assert!(matches!(
+            reader.read_line(&mut line).await,
+            ReadLineResult::TooLong(4)
+        ));
+        assert_eq!(line.to_string(), "abcd");
+        assert_eq!(reader.read_line(&mut line).await.unwrap(), 2);
+        assert_eq!(line.to_string(), "ab");
+        assert!(reader.read_line(&mut line).await.is_eof());
+
+        let mut reader = AsciiStreamReaderAsync::with_capacity(3, Cursor::new(data));
+        reader.set_max_line_len(Some(4));
+        let mut lines = reader.lines_buffered();
+        assert_eq!(
+            lines.next_line().await.unwrap().unwrap().to_string(),
+            "abcd"
+        );
+        assert!(lines.next_line().await.unwrap().is_err());
+        assert_eq!(lines.next_line().await.unwrap().unwrap().to_string(), "ab");
+        assert!(lines.next_line().await.is_none());
+
+        let mut reader = AsciiStreamReaderAsync::with_capacity(3, Cursor::new(data));
+        reader.set_max_line_len(Some(5));
+        assert_eq!(reader.read_until(LF, &mut line).await.unwrap(), 6);
+        let err = reader.read_until(LF, &mut line).await.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[tokio::test]
+    async fn test_io_traits() {
+        use super::*;
+        use futures::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, Cursor};
+
+        let data = b"header\r\nline 1\nline 2";
+        let mut reader = AsciiStreamReaderAsync::with_capacity(4, Cursor::new(data));
+        let mut line = AsciiString::new();
+        assert!(reader.read_line(&mut line).await.is_success());
+        assert_eq!(reader.buffer(), b"");
+        assert_eq!(reader.get_ref().position(), 8);
+
+        // the AsyncBufRead extension methods.
+        let mut text = String::new();
+        AsyncBufReadExt::read_line(&mut reader, &mut text)
+            .await
+            .unwrap();
+        assert_eq!(text, "line 1\n");
+        assert_eq!(reader.buffer(), b"l");
+
+        let mut writer = AsciiStreamWriterAsync::with_capacity(4, Vec::new());
+        futures::io::copy_buf((&mut reader).take(3), &mut writer)
+            .await
+            .unwrap();
+        // copy flushes when done.
+        assert_eq!(writer.get_ref(), b"lin");
+        writer.write_all(b"e!").await.unwrap();
+        assert_eq!(writer.buffer(), b"e!");
+        writer.close().await.unwrap();
+        assert_eq!(writer.get_ref(), b"line!");
+        assert_eq!(reader.buffer(), b"e ");
+        assert_eq!(reader.into_inner().position(), 20);
+    }
+
     #[tokio::test]
     async fn test_read_lines() {
         use super::*;
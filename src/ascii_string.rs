@@ -1,17 +1,34 @@
-use crate::ascii_group::AsciiGroupIter;
-use crate::ascii_traits::{AsciiOrdToChar, CharToAsciiOrd};
-use std::borrow::Cow;
-use std::collections::vec_deque::{Iter, IterMut};
+use crate::ascii_char::AsciiChar;
+use crate::ascii_error::AsciiError;
+use crate::ascii_lossy::LossyPolicy;
+use crate::ascii_str::AsciiStr;
+use crate::ascii_traits::{AsciiOrdToChar, CharToAsciiOrd, ToAsciiBytes};
+use crate::ascii_translators::utf8_to_latin1;
+use std::borrow::{Borrow, BorrowMut, Cow};
 use std::collections::VecDeque;
 use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
-use std::io::{Read, Write};
-use std::iter::Map;
-use std::ops::{Add, AddAssign, Index, IndexMut};
+use std::io::Write;
+use std::ops::{
+    Add, AddAssign, Deref, DerefMut, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull,
+    RangeInclusive, RangeTo, RangeToInclusive,
+};
+use std::vec::Drain;
 
 /// A String like struct that contains ASCII and Extended ASCII characters.
 /// <br>
 /// Because it accepts Extended ASCII, all u8 values are accepted.
+/// <br>
+/// `AsciiString` derefs to [`AsciiStr`], so all of the read-only methods (`len`, `iter`, `contains`...) live there,
+/// and `&AsciiString` can be passed wherever a `&AsciiStr` is expected.
+/// <br>
+/// The bytes are stored contiguously in a `Vec<u8>`, so `as_bytes()` only needs a shared reference,
+/// and converting to and from `Vec<u8>` is free.
+/// <br>
+/// Before 0.3 the bytes were stored in a `VecDeque<u8>`. The front operations (`push_front`, `pop_front`,
+/// `read`...) now shift every byte, so they are O(n) instead of O(1); for queue-like use, use an
+/// [`AsciiDeque`](crate::ascii_deque::AsciiDeque), which keeps the `VecDeque<u8>` storage and converts
+/// to and from `AsciiString` without copying.
 /// # samples
 /// ```
 /// use cj_ascii::prelude::*;
@@ -65,6 +82,17 @@ use std::ops::{Add, AddAssign, Index, IndexMut};
 /// assert_eq!(astring[0], 'G'.ascii_ord_unchecked());
 /// assert_eq!(astring.to_string(), "GHIJKL");
 /// ```
+/// Slicing
+/// ```
+/// # use cj_ascii::prelude::*;
+/// let record = AsciiString::try_from("0042SMITH     JOHN").unwrap();
+/// let id: &AsciiStr = &record[..4];
+/// let last_name = record[4..14].trim_end();
+/// assert_eq!(id.to_string(), "0042");
+/// assert_eq!(last_name.to_string(), "SMITH");
+/// assert_eq!(record.substring(14..).to_string(), "JOHN");
+/// assert!(record.get(14..20).is_none());
+/// ```
 /// Iteration
 /// ```
 /// # use cj_ascii::prelude::*;
@@ -185,7 +213,7 @@ use std::ops::{Add, AddAssign, Index, IndexMut};
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct AsciiString {
-    pub(crate) bytes: VecDeque<u8>,
+    pub(crate) bytes: Vec<u8>,
 }
 
 impl AsciiString {
@@ -193,7 +221,7 @@ impl AsciiString {
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            bytes: VecDeque::with_capacity(capacity),
+            bytes: Vec::with_capacity(capacity),
         }
     }
     /// Creates a new empty `AsciiString`.
@@ -201,14 +229,6 @@ impl AsciiString {
     pub fn new() -> Self {
         Self::default()
     }
-    /// Returns the length of the `AsciiString` in bytes.
-    pub fn len(&self) -> usize {
-        self.bytes.len()
-    }
-    /// Returns true if the `AsciiString` contains no bytes.
-    pub fn is_empty(&self) -> bool {
-        self.bytes.is_empty()
-    }
     /// Pushes a char or byte onto the end of the `AsciiString`.
     /// # Panics
     /// * If a char is supplied and is not ASCII/Extended ASCII.
@@ -224,7 +244,7 @@ impl AsciiString {
     /// //astring.push('€'); // Will panic!
     /// ```
     pub fn push<T: CharToAsciiOrd>(&mut self, value: T) {
-        self.bytes.push_back(value.ascii_ord_unchecked());
+        self.bytes.push(value.ascii_ord_unchecked());
     }
     /// Pushes a char or byte onto the end of the `AsciiString`.
     /// # Errors
@@ -240,15 +260,17 @@ impl AsciiString {
     /// astring.try_push('D').unwrap();
     /// assert!(astring.try_push('€').is_err());
     /// ```
-    pub fn try_push<T: CharToAsciiOrd + Display>(&mut self, value: T) -> Result<(), String> {
-        self.bytes.push_back(value.try_ascii_ord()?);
+    pub fn try_push<T: CharToAsciiOrd>(&mut self, value: T) -> Result<(), AsciiError> {
+        let byte = value.try_ascii_ord().map_err(|err| err.at(self.len()))?;
+        self.bytes.push(byte);
         Ok(())
     }
     /// Pops a byte from the end of the `AsciiString`.
     pub fn pop(&mut self) -> Option<u8> {
-        self.bytes.pop_back()
+        self.bytes.pop()
     }
     /// Pushes a char or byte onto the front of the `AsciiString`.
+    /// * all existing bytes are shifted to make room, so this is O(n).
     /// # Panics
     /// * If a char is supplied and is not ASCII/Extended ASCII.
     /// * using byte (u8) will never panic.
@@ -264,9 +286,10 @@ impl AsciiString {
     /// assert_eq!(astring.to_string(), "DCBA");
     /// ```
     pub fn push_front<T: CharToAsciiOrd>(&mut self, value: T) {
-        self.bytes.push_front(value.ascii_ord_unchecked());
+        self.bytes.insert(0, value.ascii_ord_unchecked());
     }
     /// Pushes a char or byte onto the front of the `AsciiString`.
+    /// * all existing bytes are shifted to make room, so this is O(n).
     /// # Errors
     /// * If a char is supplied and is not ASCII/Extended ASCII.
     /// * using byte (u8) will never error.
@@ -281,13 +304,19 @@ impl AsciiString {
     /// assert!(astring.try_push_front('€').is_err());
     /// assert_eq!(astring.to_string(), "DCBA");
     /// ```
-    pub fn try_push_front<T: CharToAsciiOrd + Display>(&mut self, value: T) -> Result<(), String> {
-        self.bytes.push_front(value.try_ascii_ord()?);
+    pub fn try_push_front<T: CharToAsciiOrd>(&mut self, value: T) -> Result<(), AsciiError> {
+        let byte = value.try_ascii_ord().map_err(|err| err.at(0))?;
+        self.bytes.insert(0, byte);
         Ok(())
     }
     /// Pops a byte from the front of the `AsciiString`.
+    /// * all remaining bytes are shifted, so this is O(n).
     pub fn pop_front(&mut self) -> Option<u8> {
-        self.bytes.pop_front()
+        if self.bytes.is_empty() {
+            None
+        } else {
+            Some(self.bytes.remove(0))
+        }
     }
     /// Pushes a char onto the end of the `AsciiString`.
     /// # Panics
@@ -318,17 +347,22 @@ impl AsciiString {
     /// astring.try_push_char('D').unwrap();
     /// assert!(astring.try_push_char('€').is_err());
     /// ```
-    pub fn try_push_char(&mut self, character: char) -> Result<(), String> {
-        self.bytes.push_back(character.try_ascii_ord()?);
+    pub fn try_push_char(&mut self, character: char) -> Result<(), AsciiError> {
+        let byte = character
+            .try_ascii_ord()
+            .map_err(|err| err.at(self.len()))?;
+        self.bytes.push(byte);
         Ok(())
     }
     /// Pushes a char onto the front of the `AsciiString`.
+    /// * all existing bytes are shifted to make room, so this is O(n).
     /// # Panics
     /// * if the char is not ASCII/Extended ASCII.
     pub fn push_front_char(&mut self, character: char) {
-        self.bytes.push_front(character.ascii_ord_unchecked());
+        self.bytes.insert(0, character.ascii_ord_unchecked());
     }
     /// Pushes a char onto the front of the `AsciiString`.
+    /// * all existing bytes are shifted to make room, so this is O(n).
     /// # Errors
     /// * if the char is not ASCII/Extended ASCII.
     /// # Examples
@@ -342,17 +376,28 @@ impl AsciiString {
     /// assert!(astring.try_push_front_char('€').is_err());
     /// assert_eq!(astring.to_string(), "DCBA");
     /// ```
-    pub fn try_push_front_char(&mut self, character: char) -> Result<(), String> {
-        self.bytes.push_front(character.try_ascii_ord()?);
+    pub fn try_push_front_char(&mut self, character: char) -> Result<(), AsciiError> {
+        let byte = character.try_ascii_ord().map_err(|err| err.at(0))?;
+        self.bytes.insert(0, byte);
         Ok(())
     }
     /// Pops a char from the end of the `AsciiString`.
     pub fn pop_char(&mut self) -> Option<char> {
-        self.bytes.pop_back().map(|byte| byte.to_ascii_char())
+        self.bytes.pop().map(|byte| byte.to_ascii_char())
     }
     /// Pops a char from the front of the `AsciiString`.
+    /// * all remaining bytes are shifted, so this is O(n).
     pub fn pop_front_char(&mut self) -> Option<char> {
-        self.bytes.pop_front().map(|byte| byte.to_ascii_char())
+        self.pop_front().map(|byte| byte.to_ascii_char())
+    }
+    /// Pops an `AsciiChar` from the end of the `AsciiString`.
+    pub fn pop_ascii_char(&mut self) -> Option<AsciiChar> {
+        self.bytes.pop().map(AsciiChar::from)
+    }
+    /// Pops an `AsciiChar` from the front of the `AsciiString`.
+    /// * all remaining bytes are shifted, so this is O(n).
+    pub fn pop_front_ascii_char(&mut self) -> Option<AsciiChar> {
+        self.pop_front().map(AsciiChar::from)
     }
     /// Pushes a string onto the end of the `AsciiString`.
     /// # Panics
@@ -370,13 +415,13 @@ impl AsciiString {
     /// astring.try_push_str("ABCD").unwrap();
     /// assert!(astring.try_push_str("€").is_err());
     /// ```
-    pub fn try_push_str(&mut self, string: &str) -> Result<(), String> {
-        let astr = AsciiString::try_from(string)?;
-        self.bytes.extend(astr.bytes);
-
-        Ok(())
+    pub fn try_push_str(&mut self, string: &str) -> Result<(), AsciiError> {
+        let len = self.bytes.len();
+        utf8_to_latin1(string, &mut self.bytes).inspect_err(|_| self.bytes.truncate(len))
     }
     /// Pushes a string onto the end of the `AsciiString`, replacing non ASCII/Extended ASCII characters with a space.
+    /// <br>
+    /// use push_str_with_policy() to choose another replacement, or to count the replaced characters.
     /// # Examples
     /// ```
     /// # use cj_ascii::prelude::*;
@@ -386,14 +431,7 @@ impl AsciiString {
     /// assert_eq!(astring.to_string(), "ABCD ");
     /// ```
     pub fn push_str_lossy(&mut self, string: &str) {
-        let str_len = string.len();
-        let my_remaining_capacity = self.remaining_capacity();
-        if str_len > my_remaining_capacity {
-            self.bytes.reserve(str_len - my_remaining_capacity);
-        }
-        for character in string.chars() {
-            self.push(character.ascii_ord_or(32));
-        }
+        self.push_str_with_policy(string, LossyPolicy::default());
     }
     /// Pushes an `AsciiString` onto the end of the `AsciiString`.
     pub fn push_ascii_string(&mut self, string: &AsciiString) {
@@ -404,6 +442,150 @@ impl AsciiString {
     pub fn push_bytes(&mut self, bytes: &[u8]) {
         self.bytes.extend(bytes);
     }
+    /// Inserts a char or byte at the given byte index.
+    /// * all bytes after the index are shifted, so this is O(n).
+    /// # Panics
+    /// * If a char is supplied and is not ASCII/Extended ASCII.
+    /// * If the index is greater than the length of the `AsciiString`.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("ACD").unwrap();
+    /// astring.insert(1, 'B');
+    /// astring.insert(4, 69);
+    /// assert_eq!(astring.to_string(), "ABCDE");
+    /// ```
+    pub fn insert<T: CharToAsciiOrd>(&mut self, index: usize, value: T) {
+        self.bytes.insert(index, value.ascii_ord_unchecked());
+    }
+    /// Inserts a char or byte at the given byte index.
+    /// # Errors
+    /// * If a char is supplied and is not ASCII/Extended ASCII.
+    /// # Panics
+    /// * If the index is greater than the length of the `AsciiString`.
+    pub fn try_insert<T: CharToAsciiOrd>(
+        &mut self,
+        index: usize,
+        value: T,
+    ) -> Result<(), AsciiError> {
+        let byte = value.try_ascii_ord().map_err(|err| err.at(index))?;
+        self.bytes.insert(index, byte);
+        Ok(())
+    }
+    /// Inserts a char, u8, &str, `String`, `AsciiString` or `&AsciiStr` at the given byte index.
+    /// # Panics
+    /// * If the value contains any non ASCII/Extended ASCII characters.
+    /// * If the index is greater than the length of the `AsciiString`.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("Hello!").unwrap();
+    /// astring.insert_str(5, " World");
+    /// assert_eq!(astring.to_string(), "Hello World!");
+    /// let other = astring.clone();
+    /// astring.insert_str(0, &other);
+    /// assert_eq!(astring.to_string(), "Hello World!Hello World!");
+    /// ```
+    pub fn insert_str<T: ToAsciiBytes>(&mut self, index: usize, value: T) {
+        self.splice(index..index, &value.ascii_bytes_unchecked());
+    }
+    /// Inserts a char, u8, &str, `String`, `AsciiString` or `&AsciiStr` at the given byte index.
+    /// * the `AsciiString` is left unchanged on error.
+    /// # Errors
+    /// * If the value contains any non ASCII/Extended ASCII characters.
+    /// # Panics
+    /// * If the index is greater than the length of the `AsciiString`.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("Hello!").unwrap();
+    /// let error = astring.try_insert_str(5, " W€rld").unwrap_err();
+    /// assert_eq!(error.char_index(), 2);
+    /// assert_eq!(astring.to_string(), "Hello!");
+    /// ```
+    pub fn try_insert_str<T: ToAsciiBytes>(
+        &mut self,
+        index: usize,
+        value: T,
+    ) -> Result<(), AsciiError> {
+        self.splice(index..index, &value.try_ascii_bytes()?);
+        Ok(())
+    }
+    /// Removes and returns the byte at the given index.
+    /// * all bytes after the index are shifted, so this is O(n).
+    /// # Panics
+    /// * If the index is out of bounds.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("ABXC").unwrap();
+    /// assert_eq!(astring.remove(2), b'X');
+    /// assert_eq!(astring.to_string(), "ABC");
+    /// ```
+    pub fn remove(&mut self, index: usize) -> u8 {
+        self.bytes.remove(index)
+    }
+    /// Retains only the bytes for which the predicate returns true, removing the rest in place.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("a1b2c3").unwrap();
+    /// astring.retain(|b| !b.is_ascii_digit());
+    /// assert_eq!(astring.to_string(), "abc");
+    /// ```
+    pub fn retain<F: FnMut(u8) -> bool>(&mut self, mut predicate: F) {
+        self.bytes.retain(|byte| predicate(*byte));
+    }
+    /// Removes the bytes in the given range, returning them as an iterator.
+    /// * the range is removed even if the iterator is not fully consumed.
+    /// # Panics
+    /// * If the range is out of bounds, or its start is greater than its end.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("Hello World!").unwrap();
+    /// let drained = AsciiString::from(astring.drain(5..11).collect::<Vec<u8>>());
+    /// assert_eq!(drained.to_string(), " World");
+    /// assert_eq!(astring.to_string(), "Hello!");
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, u8> {
+        self.bytes.drain(range)
+    }
+    /// Replaces the bytes in the given range with a char, u8, &str, `String`, `AsciiString` or `&AsciiStr`.
+    /// * the replacement does not need to be the same length as the range.
+    /// # Panics
+    /// * If the value contains any non ASCII/Extended ASCII characters.
+    /// * If the range is out of bounds, or its start is greater than its end.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("Hello World!").unwrap();
+    /// astring.replace_range(6..11, "Rust");
+    /// assert_eq!(astring.to_string(), "Hello Rust!");
+    /// astring.replace_range(..5, 'Y');
+    /// assert_eq!(astring.to_string(), "Y Rust!");
+    /// ```
+    pub fn replace_range<R: RangeBounds<usize>, T: ToAsciiBytes>(&mut self, range: R, value: T) {
+        self.splice(range, &value.ascii_bytes_unchecked());
+    }
+    /// Replaces the bytes in the given range with a char, u8, &str, `String`, `AsciiString` or `&AsciiStr`.
+    /// * the `AsciiString` is left unchanged on error.
+    /// # Errors
+    /// * If the value contains any non ASCII/Extended ASCII characters.
+    /// # Panics
+    /// * If the range is out of bounds, or its start is greater than its end.
+    pub fn try_replace_range<R: RangeBounds<usize>, T: ToAsciiBytes>(
+        &mut self,
+        range: R,
+        value: T,
+    ) -> Result<(), AsciiError> {
+        self.splice(range, &value.try_ascii_bytes()?);
+        Ok(())
+    }
+    #[inline]
+    fn splice<R: RangeBounds<usize>>(&mut self, range: R, bytes: &[u8]) {
+        self.bytes.splice(range, bytes.iter().copied());
+    }
     /// Clears the `AsciiString`, removing all bytes.
     #[inline]
     pub fn clear(&mut self) {
@@ -413,6 +595,82 @@ impl AsciiString {
     pub fn truncate(&mut self, len: usize) {
         self.bytes.truncate(len);
     }
+    /// Pads the start of the `AsciiString` with the fill char or byte, up to `width` bytes.
+    /// * does nothing if the `AsciiString` is already `width` bytes or longer.
+    /// # Panics
+    /// * If a char is supplied and is not ASCII/Extended ASCII.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("42").unwrap();
+    /// astring.pad_left(5, '0');
+    /// assert_eq!(astring.to_string(), "00042");
+    /// ```
+    pub fn pad_left<T: CharToAsciiOrd>(&mut self, width: usize, fill: T) {
+        let padding = width.saturating_sub(self.bytes.len());
+        if padding > 0 {
+            let fill = fill.ascii_ord_unchecked();
+            self.bytes.splice(0..0, std::iter::repeat_n(fill, padding));
+        }
+    }
+    /// Pads the end of the `AsciiString` with the fill char or byte, up to `width` bytes.
+    /// * does nothing if the `AsciiString` is already `width` bytes or longer.
+    /// # Panics
+    /// * If a char is supplied and is not ASCII/Extended ASCII.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("NAME").unwrap();
+    /// astring.pad_right(8, ' ');
+    /// assert_eq!(astring.to_string(), "NAME    ");
+    /// ```
+    pub fn pad_right<T: CharToAsciiOrd>(&mut self, width: usize, fill: T) {
+        if width > self.bytes.len() {
+            let fill = fill.ascii_ord_unchecked();
+            self.bytes.resize(width, fill);
+        }
+    }
+    /// Pads both ends of the `AsciiString` with the fill char or byte, up to `width` bytes.
+    /// * when the padding can't be split evenly, the extra byte goes on the end (the same as `format!("{:^}")`).
+    /// * does nothing if the `AsciiString` is already `width` bytes or longer.
+    /// # Panics
+    /// * If a char is supplied and is not ASCII/Extended ASCII.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("ab").unwrap();
+    /// astring.center(7, '*');
+    /// assert_eq!(astring.to_string(), "**ab***");
+    /// ```
+    pub fn center<T: CharToAsciiOrd>(&mut self, width: usize, fill: T) {
+        let padding = width.saturating_sub(self.bytes.len());
+        if padding > 0 {
+            let fill = fill.ascii_ord_unchecked();
+            self.pad_left(self.bytes.len() + padding / 2, fill);
+            self.pad_right(width, fill);
+        }
+    }
+    /// Makes the `AsciiString` exactly `width` bytes long, truncating it or padding the end with the fill char or byte.
+    /// <br>
+    /// Useful for writing fixed-width record fields.
+    /// # Panics
+    /// * If a char is supplied and is not ASCII/Extended ASCII.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("Smith").unwrap();
+    /// astring.truncate_or_pad(8, ' ');
+    /// assert_eq!(astring.to_string(), "Smith   ");
+    /// astring.truncate_or_pad(3, ' ');
+    /// assert_eq!(astring.to_string(), "Smi");
+    /// ```
+    pub fn truncate_or_pad<T: CharToAsciiOrd>(&mut self, width: usize, fill: T) {
+        if width > self.bytes.len() {
+            self.pad_right(width, fill);
+        } else {
+            self.bytes.truncate(width);
+        }
+    }
     /// Returns the capacity of the `AsciiString` in bytes.
     #[inline]
     pub fn capacity(&self) -> usize {
@@ -438,25 +696,48 @@ impl AsciiString {
     pub fn shrink_to(&mut self, min_capacity: usize) {
         self.bytes.shrink_to(min_capacity);
     }
-    /// Returns a byte slice of the raw `AsciiString`.
-    ///
-    /// * Each byte represents a single char.
-    /// # note
-    /// * a mutable reference is required, but only to make the memory contiguous (if not contiguous already).
-    pub fn as_bytes(&mut self) -> &[u8] {
-        self.bytes.make_contiguous()
-        //self.bytes.as_slices().0
-    }
-    /// Returns a mutable byte slice of the raw `AsciiString`.
-    ///
-    /// * Each byte represents a single char.
-    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
-        self.bytes.make_contiguous()
-        //self.bytes.as_mut_slices().0
+    /// Returns a borrowed `AsciiStr` slice of the entire `AsciiString`.
+    #[inline]
+    pub fn as_ascii_str(&self) -> &AsciiStr {
+        AsciiStr::from_bytes(&self.bytes)
+    }
+    /// Returns a mutable borrowed `AsciiStr` slice of the entire `AsciiString`.
+    #[inline]
+    pub fn as_mut_ascii_str(&mut self) -> &mut AsciiStr {
+        AsciiStr::from_bytes_mut(&mut self.bytes)
+    }
+    /// Returns a mutable reference to the underlying `Vec<u8>`.
+    /// * this is safe, since every u8 value is a valid (Extended) ASCII character.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::try_from("ABC").unwrap();
+    /// astring.as_mut_vec().extend_from_slice(b"DEF");
+    /// assert_eq!(astring.to_string(), "ABCDEF");
+    /// ```
+    #[inline]
+    pub fn as_mut_vec(&mut self) -> &mut Vec<u8> {
+        &mut self.bytes
+    }
+    /// Converts the `AsciiString` into its underlying `Vec<u8>`, without copying.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let astring = AsciiString::try_from("ABC").unwrap();
+    /// let bytes = astring.into_bytes();
+    /// assert_eq!(bytes, [65, 66, 67]);
+    /// let astring = AsciiString::from(bytes);
+    /// assert_eq!(astring.to_string(), "ABC");
+    /// ```
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
     }
 
     /// Fills buf with the contents of the `AsciiString`, returning the number of bytes read.
     /// * this consumes the bytes read from the `AsciiString`.
+    /// * the remaining bytes are moved to the front on every call, so reading a large `AsciiString`
+    ///   through a small buf is quadratic; read from a `&AsciiStr` (a `Read`) instead.
     /// # Example
     ///```
     /// # use cj_ascii::prelude::*;
@@ -469,12 +750,14 @@ impl AsciiString {
     /// assert_eq!(result.unwrap(), 3);
     /// assert_eq!(buf, [65, 66, 67]);
     /// // astring is now empty
-    /// assert_eq!(astring.as_bytes(), []);
+    /// assert_eq!(astring.as_bytes(), [0u8; 0]);
     /// assert_eq!(astring.len(), 0);
     /// ```
     pub fn read(&mut self, buf: &mut [u8]) -> Result<usize, std::io::Error> {
-        self.bytes.make_contiguous();
-        self.bytes.read(buf)
+        let len = buf.len().min(self.bytes.len());
+        buf[..len].copy_from_slice(&self.bytes[..len]);
+        self.bytes.drain(..len);
+        Ok(len)
     }
     /// Writes buf into the `AsciiString`, returning the number of bytes written.
     /// # Example
@@ -493,133 +776,130 @@ impl AsciiString {
     pub fn write(&mut self, buf: &[u8]) -> Result<usize, std::io::Error> {
         self.bytes.write(buf)
     }
-    /// Sorts the `AsciiString` in place.
-    /// # Example
-    /// ```
-    /// # use cj_ascii::prelude::*;
-    /// let mut astring = AsciiString::try_from("DCBA").unwrap();
-    /// astring.sort();
-    /// assert_eq!(astring.to_string(), "ABCD");
-    /// ```
-    pub fn sort(&mut self) {
-        self.bytes.make_contiguous().sort_unstable();
+}
+
+/// Reads from the front of the `AsciiString`, consuming the bytes read (see `AsciiString::read`).
+/// * read_to_end() and read_to_string() take all the bytes at once.
+/// * every other read moves the remaining bytes to the front, so to stream a large `AsciiString`
+///   (`io::copy`, `BufReader`, `Take`), read from a `&AsciiStr` instead, which only advances the slice.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// use std::io::Read;
+///
+/// let mut astring = AsciiString::try_from("ABC").unwrap();
+/// let mut out = Vec::new();
+/// astring.read_to_end(&mut out).unwrap();
+/// assert_eq!(out, b"ABC");
+/// assert!(astring.is_empty());
+///
+/// let astring = AsciiString::try_from("DEF").unwrap();
+/// let mut out = Vec::new();
+/// std::io::copy(&mut astring.as_ascii_str(), &mut out).unwrap();
+/// assert_eq!(out, b"DEF");
+/// ```
+impl std::io::Read for AsciiString {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        AsciiString::read(self, buf)
     }
-    /// Returns true if the `AsciiString` contains the given char or byte.
-    /// # Panics
-    /// * If a char is supplied and is not ASCII/Extended ASCII.
-    /// * using byte (u8) will never panic.
-    /// # Examples
-    /// ```
-    /// # use cj_ascii::prelude::*;
-    /// let mut astring = AsciiString::try_from("Hello World!").unwrap();
-    /// assert!(astring.contains('H'));
-    /// assert!(astring.contains(72));
-    /// // assert!(!astring.contains('€')); // Will panic!
-    pub fn contains<T: CharToAsciiOrd>(&self, value: T) -> bool {
-        self.bytes.contains(&value.ascii_ord_unchecked())
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> std::io::Result<usize> {
+        buf.extend_from_slice(&self.bytes);
+        let len = self.bytes.len();
+        self.bytes.clear();
+        Ok(len)
+    }
+    fn read_to_string(&mut self, buf: &mut String) -> std::io::Result<usize> {
+        let Some(string) = self.as_str() else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "stream did not contain valid UTF-8",
+            ));
+        };
+        buf.push_str(string);
+        let len = self.bytes.len();
+        self.bytes.clear();
+        Ok(len)
     }
-    /// Returns a u8 iterator over the `AsciiString`.
-    /// # Examples
-    /// ```
-    /// # use cj_ascii::prelude::*;
-    /// let astring = AsciiString::try_from("Hello World!").unwrap();
-    /// let mut iter = astring.iter();
-    /// assert_eq!(iter.next(), Some(&72));
-    /// assert_eq!(iter.next(), Some(&101));
-    /// assert_eq!(iter.next(), Some(&108));
-    /// assert_eq!(iter.next(), Some(&108));
-    /// assert_eq!(iter.next(), Some(&111));
-    /// assert_eq!(iter.next(), Some(&32));
-    /// assert_eq!(iter.next(), Some(&87));
-    /// assert_eq!(iter.next(), Some(&111));
-    /// assert_eq!(iter.next(), Some(&114));
-    /// assert_eq!(iter.next(), Some(&108));
-    /// assert_eq!(iter.next(), Some(&100));
-    /// assert_eq!(iter.next(), Some(&33));
-    /// assert_eq!(iter.next(), None);
-    /// ```
+}
+
+/// Appends to the `AsciiString` (see `AsciiString::write`).
+/// <br>
+/// Every byte is a valid (Extended) ASCII character, so writing never fails.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// use std::io::Write;
+///
+/// let mut astring = AsciiString::new();
+/// write!(astring, "{}-{}", 1, 2).unwrap();
+/// astring.write_all(b"-3").unwrap();
+/// assert_eq!(astring.to_string(), "1-2-3");
+/// ```
+impl Write for AsciiString {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        AsciiString::write(self, buf)
+    }
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.bytes.extend_from_slice(buf);
+        Ok(())
+    }
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Deref for AsciiString {
+    type Target = AsciiStr;
+
     #[inline]
-    pub fn iter(&self) -> Iter<u8> {
-        self.bytes.iter()
+    fn deref(&self) -> &Self::Target {
+        self.as_ascii_str()
     }
+}
 
-    /// Returns a mutable u8 iterator over the `AsciiString`.
-    /// # Examples
-    /// ```
-    /// # use cj_ascii::prelude::*;
-    /// let mut astring = AsciiString::try_from("Hello World!").unwrap();
-    /// let mut iter = astring.iter_mut();
-    /// assert_eq!(iter.next(), Some(&mut 72));
-    /// assert_eq!(iter.next(), Some(&mut 101));
-    /// assert_eq!(iter.next(), Some(&mut 108));
-    /// assert_eq!(iter.next(), Some(&mut 108));
-    /// assert_eq!(iter.next(), Some(&mut 111));
-    /// assert_eq!(iter.next(), Some(&mut 32));
-    /// assert_eq!(iter.next(), Some(&mut 87));
-    /// assert_eq!(iter.next(), Some(&mut 111));
-    /// assert_eq!(iter.next(), Some(&mut 114));
-    /// assert_eq!(iter.next(), Some(&mut 108));
-    /// assert_eq!(iter.next(), Some(&mut 100));
-    /// assert_eq!(iter.next(), Some(&mut 33));
-    /// assert_eq!(iter.next(), None);
-    /// ```
-    /// ```
-    /// # use cj_ascii::prelude::*;
-    /// let mut astring = AsciiString::try_from("Hello World!").unwrap();
-    /// for byte in astring.iter_mut() {
-    ///    if *byte == 32 {
-    ///       *byte = 95;
-    ///   }
-    /// }
-    /// assert_eq!(astring, AsciiString::try_from("Hello_World!").unwrap());
-    /// ```
+impl DerefMut for AsciiString {
     #[inline]
-    pub fn iter_mut(&mut self) -> IterMut<u8> {
-        self.bytes.iter_mut()
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_ascii_str()
     }
+}
 
-    /// Returns a char iterator over the `AsciiString`.
-    /// # Examples
-    /// ```
-    /// # use cj_ascii::prelude::*;
-    /// let astring = AsciiString::try_from("Hello World!").unwrap();
-    /// let mut iter = astring.iter_ascii();
-    /// assert_eq!(iter.next(), Some('H'));
-    /// assert_eq!(iter.next(), Some('e'));
-    /// assert_eq!(iter.next(), Some('l'));
-    /// assert_eq!(iter.next(), Some('l'));
-    /// assert_eq!(iter.next(), Some('o'));
-    /// assert_eq!(iter.next(), Some(' '));
-    /// assert_eq!(iter.next(), Some('W'));
-    /// assert_eq!(iter.next(), Some('o'));
-    /// assert_eq!(iter.next(), Some('r'));
-    /// assert_eq!(iter.next(), Some('l'));
-    /// assert_eq!(iter.next(), Some('d'));
-    /// assert_eq!(iter.next(), Some('!'));
-    /// assert_eq!(iter.next(), None);
-    /// ```  
+impl AsRef<AsciiStr> for AsciiString {
     #[inline]
-    pub fn iter_ascii(&self) -> Map<Iter<'_, u8>, fn(&u8) -> char> {
-        self.bytes.iter().map(|byte| byte.to_ascii_char())
+    fn as_ref(&self) -> &AsciiStr {
+        self.as_ascii_str()
     }
-    /// Returns an AsciiGroup iterator over the `AsciiString`.
-    /// # Examples
-    /// ```
-    /// # use cj_ascii::prelude::*;
-    /// let astring = AsciiString::try_from("Hello World!").unwrap();
-    /// for x in astring.iter_ascii_group() {
-    ///     match x {
-    ///        AsciiGroup::PrintableCtrl(_) => println!("PrintableCtrl: {}", x.as_char()),
-    ///        AsciiGroup::Printable(_) => println!("PrintableAscii: {}", x.as_char()),
-    ///        AsciiGroup::NonPrintableCtrl(_) => println!("NonPrintableCtrl: {}", x.as_byte()),
-    ///        AsciiGroup::Extended(_) => println!("Extended: {}", x.as_byte()),
-    ///     }
-    /// }
-    /// ```
+}
+
+impl AsMut<AsciiStr> for AsciiString {
+    #[inline]
+    fn as_mut(&mut self) -> &mut AsciiStr {
+        self.as_mut_ascii_str()
+    }
+}
+
+impl AsRef<[u8]> for AsciiString {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl Borrow<AsciiStr> for AsciiString {
+    #[inline]
+    fn borrow(&self) -> &AsciiStr {
+        self.as_ascii_str()
+    }
+}
+
+impl BorrowMut<AsciiStr> for AsciiString {
     #[inline]
-    pub fn iter_ascii_group(&self) -> AsciiGroupIter {
-        let iter = self.iter();
-        AsciiGroupIter::new(iter)
+    fn borrow_mut(&mut self) -> &mut AsciiStr {
+        self.as_mut_ascii_str()
     }
 }
 
@@ -638,6 +918,36 @@ impl IndexMut<usize> for AsciiString {
     }
 }
 
+macro_rules! impl_range_index {
+    ($($range:ty),*) => {
+        $(
+            impl Index<$range> for AsciiString {
+                type Output = AsciiStr;
+                #[inline]
+                fn index(&self, index: $range) -> &Self::Output {
+                    &self.as_ascii_str()[index]
+                }
+            }
+
+            impl IndexMut<$range> for AsciiString {
+                #[inline]
+                fn index_mut(&mut self, index: $range) -> &mut Self::Output {
+                    &mut self.as_mut_ascii_str()[index]
+                }
+            }
+        )*
+    };
+}
+
+impl_range_index!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeInclusive<usize>,
+    RangeToInclusive<usize>,
+    RangeFull
+);
+
 impl Add<AsciiString> for AsciiString {
     type Output = Self;
 
@@ -709,7 +1019,7 @@ impl Add<char> for AsciiString {
     /// Panics if the `char` is not ASCII/Extended ASCII.
     #[inline(always)]
     fn add(mut self, rhs: char) -> Self::Output {
-        self.bytes.push_back(rhs.ascii_ord_unchecked());
+        self.bytes.push(rhs.ascii_ord_unchecked());
         self
     }
 }
@@ -721,7 +1031,17 @@ impl Add<&char> for AsciiString {
     /// Panics if the `char` is not ASCII/Extended ASCII.
     #[inline(always)]
     fn add(mut self, rhs: &char) -> Self::Output {
-        self.bytes.push_back(rhs.ascii_ord_unchecked());
+        self.bytes.push(rhs.ascii_ord_unchecked());
+        self
+    }
+}
+
+impl Add<AsciiChar> for AsciiString {
+    type Output = Self;
+    /// Concatenates an `AsciiString` and an `AsciiChar`.
+    #[inline(always)]
+    fn add(mut self, rhs: AsciiChar) -> Self::Output {
+        self.bytes.push(rhs.as_byte());
         self
     }
 }
@@ -768,7 +1088,7 @@ impl AddAssign<char> for AsciiString {
     /// Panics if the `char` is not ASCII/Extended ASCII.
     #[inline(always)]
     fn add_assign(&mut self, rhs: char) {
-        self.bytes.push_back(rhs.ascii_ord_unchecked());
+        self.bytes.push(rhs.ascii_ord_unchecked());
     }
 }
 
@@ -778,44 +1098,68 @@ impl AddAssign<&char> for AsciiString {
     /// Panics if the `char` is not ASCII/Extended ASCII.
     #[inline(always)]
     fn add_assign(&mut self, rhs: &char) {
-        self.bytes.push_back(rhs.ascii_ord_unchecked());
+        self.bytes.push(rhs.ascii_ord_unchecked());
+    }
+}
+
+impl AddAssign<AsciiChar> for AsciiString {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: AsciiChar) {
+        self.bytes.push(rhs.as_byte());
     }
 }
 
 impl AddAssign<u8> for AsciiString {
     #[inline(always)]
     fn add_assign(&mut self, rhs: u8) {
-        self.bytes.push_back(rhs);
+        self.bytes.push(rhs);
+    }
+}
+
+impl ToAsciiBytes for AsciiString {
+    #[inline(always)]
+    fn try_ascii_bytes(&self) -> Result<Cow<'_, [u8]>, AsciiError> {
+        Ok(Cow::Borrowed(&self.bytes))
+    }
+}
+
+impl ToAsciiBytes for &AsciiString {
+    #[inline(always)]
+    fn try_ascii_bytes(&self) -> Result<Cow<'_, [u8]>, AsciiError> {
+        Ok(Cow::Borrowed(&self.bytes))
     }
 }
 
 impl From<AsciiString> for String {
     fn from(value: AsciiString) -> Self {
-        let mut result = String::with_capacity(value.bytes.len());
-        for byte in value.bytes {
-            result.push(byte.to_ascii_char());
+        if value.bytes.is_ascii() {
+            // SAFETY: 7-bit ASCII is valid UTF-8, so the bytes are reused without copying.
+            return unsafe { String::from_utf8_unchecked(value.bytes) };
         }
-        result
+        String::from(value.as_ascii_str())
     }
 }
 
 impl From<&AsciiString> for String {
+    #[inline]
     fn from(value: &AsciiString) -> Self {
-        let mut result = String::with_capacity(value.bytes.len());
-        for byte in &value.bytes {
-            result.push(byte.to_ascii_char());
-        }
-        result
+        String::from(value.as_ascii_str())
     }
 }
 
 impl From<&AsciiString> for AsciiString {
     fn from(value: &AsciiString) -> Self {
-        let mut result = Self::with_capacity(value.bytes.len());
-        for byte in &value.bytes {
-            result.bytes.push_back(*byte);
+        Self {
+            bytes: value.bytes.clone(),
+        }
+    }
+}
+
+impl From<&AsciiStr> for AsciiString {
+    fn from(value: &AsciiStr) -> Self {
+        Self {
+            bytes: value.bytes.to_vec(),
         }
-        result
     }
 }
 
@@ -823,27 +1167,18 @@ impl From<&AsciiString> for AsciiString {
 //     fn from(value: String) -> Self {
 //         let mut result = Self::with_capacity(value.len());
 //         for character in value.chars() {
-//             result.bytes.push_back(character.ascii_ord_unchecked());
+//             result.bytes.push(character.ascii_ord_unchecked());
 //         }
 //         result
 //     }
 // }
 
 impl TryFrom<String> for AsciiString {
-    type Error = String;
+    type Error = AsciiError;
 
+    #[inline]
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        let mut result = Self::with_capacity(value.len());
-        for (inx, character) in value.chars().enumerate() {
-            if let Some(character) = character.ascii_ord() {
-                result.bytes.push_back(character);
-            } else {
-                return Err(format!(
-                    r#"Non-ASCII character "{character}" found at index {inx}"#
-                ));
-            }
-        }
-        Ok(result)
+        Self::try_from(value.as_str())
     }
 }
 
@@ -851,7 +1186,7 @@ impl TryFrom<String> for AsciiString {
 //     fn from(value: &str) -> Self {
 //         let mut result = Self::with_capacity(value.len());
 //         for character in value.chars() {
-//             // result.bytes.push_back(character.ascii_ord_unchecked());
+//             // result.bytes.push(character.ascii_ord_unchecked());
 //             result
 //                 .bytes
 //                 .push_back(character.ascii_ord().expect("Non-ASCII character found"));
@@ -860,20 +1195,14 @@ impl TryFrom<String> for AsciiString {
 //     }
 // }
 
+/// Works on 16 byte blocks: 7-bit ASCII blocks are copied as is, and Latin-1 characters
+/// are decoded straight from their two byte UTF-8 sequences.
 impl TryFrom<&str> for AsciiString {
-    type Error = String;
+    type Error = AsciiError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let mut result = Self::with_capacity(value.len());
-        for (inx, character) in value.chars().enumerate() {
-            if let Some(character) = character.ascii_ord() {
-                result.bytes.push_back(character);
-            } else {
-                return Err(format!(
-                    r#"Non-ASCII character "{character}" found at index {inx}"#
-                ));
-            }
-        }
+        let mut result = Self::new();
+        utf8_to_latin1(value, &mut result.bytes)?;
         Ok(result)
     }
 }
@@ -882,71 +1211,53 @@ impl TryFrom<&str> for AsciiString {
 //     fn from(value: &String) -> Self {
 //         let mut result = Self::with_capacity(value.len());
 //         for character in value.chars() {
-//             result.bytes.push_back(character.ascii_ord_unchecked());
+//             result.bytes.push(character.ascii_ord_unchecked());
 //         }
 //         result
 //     }
 // }
 
 impl TryFrom<&String> for AsciiString {
-    type Error = String;
+    type Error = AsciiError;
 
+    #[inline]
     fn try_from(value: &String) -> Result<Self, Self::Error> {
-        let mut result = Self::with_capacity(value.len());
-        for (inx, character) in value.chars().enumerate() {
-            if let Some(character) = character.ascii_ord() {
-                result.bytes.push_back(character);
-            } else {
-                return Err(format!(
-                    r#"Non-ASCII character "{character}" found at index {inx}"#
-                ));
-            }
-        }
-        Ok(result)
+        Self::try_from(value.as_str())
     }
 }
 
 impl TryFrom<Cow<'_, str>> for AsciiString {
-    type Error = String;
+    type Error = AsciiError;
 
+    #[inline]
     fn try_from(value: Cow<str>) -> Result<Self, Self::Error> {
-        let mut result = Self::with_capacity(value.len());
-        for (inx, character) in value.chars().enumerate() {
-            if let Some(character) = character.ascii_ord() {
-                result.bytes.push_back(character);
-            } else {
-                return Err(format!(
-                    r#"Non-ASCII character "{character}" found at index {inx}"#
-                ));
-            }
-        }
-        Ok(result)
+        Self::try_from(value.as_ref())
     }
 }
 
 impl From<&AsciiString> for Vec<u8> {
     fn from(value: &AsciiString) -> Self {
-        value.bytes.clone().into()
+        value.bytes.clone()
     }
 }
 
 impl From<AsciiString> for Vec<u8> {
     #[inline]
     fn from(value: AsciiString) -> Self {
-        value.bytes.into()
+        value.bytes
     }
 }
 
 impl From<&AsciiString> for VecDeque<u8> {
     fn from(value: &AsciiString) -> Self {
-        value.bytes.clone()
+        value.bytes.clone().into()
     }
 }
 
 impl From<AsciiString> for VecDeque<u8> {
     #[inline]
     fn from(value: AsciiString) -> Self {
-        value.bytes
+        value.bytes.into()
     }
 }
 
@@ -993,16 +1304,16 @@ impl From<AsciiString> for VecDeque<char> {
 impl From<VecDeque<u8>> for AsciiString {
     #[inline]
     fn from(value: VecDeque<u8>) -> Self {
-        Self { bytes: value }
+        Self {
+            bytes: value.into(),
+        }
     }
 }
 
 impl From<Vec<u8>> for AsciiString {
     #[inline]
     fn from(value: Vec<u8>) -> Self {
-        Self {
-            bytes: value.into(),
-        }
+        Self { bytes: value }
     }
 }
 
@@ -1017,21 +1328,21 @@ impl From<&[u8]> for AsciiString {
 // impl From<char> for AsciiString {
 //     fn from(value: char) -> Self {
 //         let mut result = Self::with_capacity(1);
-//         result.bytes.push_back(value.ascii_ord_unchecked());
+//         result.bytes.push(value.ascii_ord_unchecked());
 //         result
 //     }
 // }
 
 impl TryFrom<char> for AsciiString {
-    type Error = String;
+    type Error = AsciiError;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         let mut result = Self::with_capacity(1);
 
         if let Some(character) = value.ascii_ord() {
-            result.bytes.push_back(character);
+            result.bytes.push(character);
         } else {
-            return Err(format!(r#"Non-ASCII character "{value}" found"#));
+            return Err(AsciiError::non_ascii(value, 0, 0));
         }
 
         Ok(result)
@@ -1039,15 +1350,15 @@ impl TryFrom<char> for AsciiString {
 }
 
 impl TryFrom<&char> for AsciiString {
-    type Error = String;
+    type Error = AsciiError;
 
     fn try_from(value: &char) -> Result<Self, Self::Error> {
         let mut result = Self::with_capacity(1);
 
         if let Some(character) = value.ascii_ord() {
-            result.bytes.push_back(character);
+            result.bytes.push(character);
         } else {
-            return Err(format!(r#"Non-ASCII character "{value}" found"#));
+            return Err(AsciiError::non_ascii(*value, 0, 0));
         }
 
         Ok(result)
@@ -1055,20 +1366,16 @@ impl TryFrom<&char> for AsciiString {
 }
 
 impl Display for AsciiString {
+    #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        for byte in &self.bytes {
-            write!(f, "{}", byte.to_ascii_char())?;
-        }
-        Ok(())
+        Display::fmt(self.as_ascii_str(), f)
     }
 }
 
 impl Debug for AsciiString {
+    #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        for byte in &self.bytes {
-            write!(f, "{}", byte.to_ascii_char())?;
-        }
-        Ok(())
+        Debug::fmt(self.as_ascii_str(), f)
     }
 }
 
@@ -1221,6 +1528,35 @@ mod test {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_try_push_str_error() {
+        use crate::ascii_error::AsciiErrorKind;
+        use crate::ascii_string::AsciiString;
+        let mut string = AsciiString::new();
+        let error = string.try_push_str("ab€c").unwrap_err();
+        assert_eq!(error.kind(), AsciiErrorKind::NonAscii);
+        assert_eq!(error.character(), '€');
+        assert_eq!(error.char_index(), 2);
+        assert_eq!(error.byte_index(), 2);
+        assert!(string.is_empty());
+
+        let error = string.try_push('€').unwrap_err();
+        assert_eq!(error.character(), '€');
+
+        // single character errors report where the character would have gone.
+        string.push_str("abc");
+        let error = string.try_push('€').unwrap_err();
+        assert_eq!((error.char_index(), error.byte_index()), (3, 3));
+        let error = string.try_insert(1, '€').unwrap_err();
+        assert_eq!((error.character(), error.char_index()), ('€', 1));
+        let error = string.try_push_front_char('€').unwrap_err();
+        assert_eq!(error.char_index(), 0);
+        let error = string
+            .try_push_with('€', crate::ascii_codepage::CodePage::Cp437)
+            .unwrap_err();
+        assert_eq!(error.char_index(), 3);
+    }
+
     #[test]
     fn test_add_assign_str() {
         use crate::ascii_string::AsciiString;
@@ -1271,6 +1607,140 @@ mod test {
         assert_eq!(&string.to_string(), "ABCDEFABCDEF");
     }
 
+    #[test]
+    fn test_ascii_char() {
+        use crate::ascii_string::AsciiString;
+        let mut string = AsciiString::new();
+        string.push(AsciiChar::LATIN_CAPITAL_LETTER_A);
+        string.try_push(AsciiChar::LATIN_CAPITAL_LETTER_B).unwrap();
+        string.push_front(AsciiChar::SPACE);
+        string += AsciiChar::LATIN_CAPITAL_LETTER_C;
+        let mut string = string + AsciiChar::EXT_128;
+        assert_eq!(string.as_bytes(), [32, 65, 66, 67, 128]);
+        assert_eq!(string.pop_ascii_char(), Some(AsciiChar::EXT_128));
+        assert_eq!(string.pop_front_ascii_char(), Some(AsciiChar::SPACE));
+        assert_eq!(
+            string.get_ascii_char(2),
+            Some(AsciiChar::LATIN_CAPITAL_LETTER_C)
+        );
+        assert!(string.contains(AsciiChar::LATIN_CAPITAL_LETTER_B));
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut string = AsciiString::try_from("bd").unwrap();
+        string.insert(0, 'a');
+        string.insert(2, b'c');
+        string.insert(4, AsciiChar::LATIN_SMALL_LETTER_E);
+        assert_eq!(string.to_string(), "abcde");
+        assert!(string.try_insert(0, '€').is_err());
+        assert_eq!(string.remove(4), b'e');
+        assert_eq!(string.remove(0), b'a');
+        assert_eq!(string.to_string(), "bcd");
+
+        string.insert_str(3, "éf");
+        string.insert_str(0, String::from("a"));
+        string.insert_str(1, AsciiString::from(vec![b'-']));
+        assert_eq!(string.to_string(), "a-bcdéf");
+        let error = string.try_insert_str(0, "x€").unwrap_err();
+        assert_eq!(error.character(), '€');
+        assert_eq!(error.char_index(), 1);
+        assert_eq!(string.to_string(), "a-bcdéf");
+    }
+
+    #[test]
+    fn test_replace() {
+        let samples = ["", "aaa", "abcabc", "xabcx", "ab", "bcbc"];
+        for sample in samples {
+            let string = AsciiString::try_from(sample).unwrap();
+            for (from, to) in [("a", "z"), ("bc", ""), ("abc", "éé"), ("", "-")] {
+                assert_eq!(
+                    string.replace(from, to).to_string(),
+                    sample.replace(from, to)
+                );
+                for count in 0..3 {
+                    assert_eq!(
+                        string.replacen(from, to, count).to_string(),
+                        sample.replacen(from, to, count)
+                    );
+                }
+            }
+        }
+        let string = AsciiString::try_from("a b c").unwrap();
+        assert_eq!(
+            string.replace(' ', AsciiChar::LOW_LINE).to_string(),
+            "a_b_c"
+        );
+        assert_eq!(string.replace(b' ', &string).to_string(), "aa b cba b cc");
+    }
+
+    #[test]
+    fn test_replace_range() {
+        let mut string = AsciiString::try_from("Hello World!").unwrap();
+        string.replace_range(6..=10, "There");
+        assert_eq!(string.to_string(), "Hello There!");
+        string.replace_range(5.., "");
+        assert_eq!(string.to_string(), "Hello");
+        assert!(string.try_replace_range(.., '€').is_err());
+        assert_eq!(string.to_string(), "Hello");
+        string.replace_range(.., 'ÿ');
+        assert_eq!(string.to_string(), "ÿ");
+    }
+
+    #[test]
+    fn test_retain_drain() {
+        let mut string = AsciiString::try_from("a1 b2 c3").unwrap();
+        string.retain(|b| b.is_ascii_alphanumeric());
+        assert_eq!(string.to_string(), "a1b2c3");
+        let drained: Vec<u8> = string.drain(2..4).collect();
+        assert_eq!(drained, b"b2");
+        assert_eq!(string.to_string(), "a1c3");
+        // dropping the iterator early still removes the whole range.
+        let mut drain = string.drain(..3);
+        assert_eq!(drain.next(), Some(b'a'));
+        drop(drain);
+        assert_eq!(string.to_string(), "3");
+    }
+
+    #[test]
+    fn test_trim() {
+        let string = AsciiString::try_from("\u{A0} \t abc \u{0B}\u{85}\r\n").unwrap();
+        assert_eq!(string.trim().to_string(), "abc");
+        assert_eq!(string.trim_start().to_string(), "abc \u{0B}\u{85}\r\n");
+        assert_eq!(string.trim_end().to_string(), "\u{A0} \t abc");
+        assert_eq!(
+            string.trim_ascii().to_string(),
+            "\u{A0} \t abc \u{0B}\u{85}"
+        );
+
+        let string = AsciiString::try_from("   ").unwrap();
+        assert!(string.trim().is_empty());
+        assert!(string.trim_matches("").len() == 3);
+        let string = AsciiString::try_from("xyxyaxyxyx").unwrap();
+        assert_eq!(string.trim_matches("xy").to_string(), "axyxyx");
+        assert_eq!(string.trim_end_matches("yx").to_string(), "xyxyax");
+        assert_eq!(string.trim_matches('x').to_string(), "yxyaxyxy");
+    }
+
+    #[test]
+    fn test_pad() {
+        let mut string = AsciiString::try_from("abc").unwrap();
+        string.pad_left(2, ' ');
+        string.pad_right(3, ' ');
+        string.center(1, ' ');
+        assert_eq!(string.to_string(), "abc");
+        string.center(6, b'.');
+        assert_eq!(string.to_string(), ".abc..");
+        string.pad_left(8, 'é');
+        assert_eq!(string.to_string(), "éé.abc..");
+        string.truncate_or_pad(10, AsciiChar::SPACE);
+        assert_eq!(string.to_string(), "éé.abc..  ");
+        string.truncate_or_pad(0, ' ');
+        assert!(string.is_empty());
+        string.center(3, '-');
+        assert_eq!(string.to_string(), "---");
+    }
+
     #[test]
     fn test_sort() {
         use crate::ascii_string::AsciiString;
@@ -1344,7 +1814,7 @@ mod test {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 3);
         assert_eq!(buf, [65, 66, 67]);
-        assert_eq!(string.as_bytes(), []);
+        assert_eq!(string.as_bytes(), [0u8; 0]);
         assert_eq!(string.len(), 0);
     }
 
@@ -1366,7 +1836,7 @@ mod test {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 1);
         assert_eq!(buf, [67, 0]);
-        assert_eq!(string.as_bytes(), []);
+        assert_eq!(string.as_bytes(), [0u8; 0]);
         assert_eq!(string.len(), 0);
 
         buf.fill(0);
@@ -1374,7 +1844,7 @@ mod test {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 0);
         assert_eq!(buf, [0, 0]);
-        assert_eq!(string.as_bytes(), []);
+        assert_eq!(string.as_bytes(), [0u8; 0]);
         assert_eq!(string.len(), 0);
     }
 
@@ -1388,7 +1858,7 @@ mod test {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 3);
         assert_eq!(buf, [65, 66, 67, 0]);
-        assert_eq!(string.as_bytes(), []);
+        assert_eq!(string.as_bytes(), [0u8; 0]);
         assert_eq!(string.len(), 0);
     }
 
@@ -1404,6 +1874,59 @@ mod test {
         assert_eq!(string.len(), 3);
     }
 
+    #[test]
+    fn test_io_traits() {
+        use std::io::{BufRead, BufReader, Read};
+
+        let mut string = AsciiString::new();
+        std::io::copy(&mut &b"line 1\nline 2\xff"[..], &mut string).unwrap();
+        assert_eq!(string.len(), 14);
+
+        let lines: Vec<String> = BufReader::new(Read::take(&mut string, 7))
+            .lines()
+            .map(|line| line.unwrap())
+            .collect();
+        assert_eq!(lines, ["line 1"]);
+        assert_eq!(string.to_string(), "line 2ÿ");
+
+        // Extended bytes are not UTF-8; nothing is consumed.
+        assert!(string.read_to_string(&mut String::new()).is_err());
+        let mut out = Vec::new();
+        assert_eq!(string.read_to_end(&mut out).unwrap(), 7);
+        assert_eq!(out, b"line 2\xff");
+        assert!(string.is_empty());
+
+        // a large string streamed through a small buffer, without consuming it.
+        let string = AsciiString::from(vec![b'x'; 100_000]);
+        let mut reader = BufReader::with_capacity(16, string.as_ascii_str());
+        let mut out = AsciiString::new();
+        assert_eq!(std::io::copy(&mut reader, &mut out).unwrap(), 100_000);
+        assert_eq!(out, string);
+    }
+
+    #[test]
+    fn test_as_bytes_shared() {
+        use crate::ascii_string::AsciiString;
+        let mut string = AsciiString::new();
+        string += "BC";
+        string.push_front('A');
+        let shared = &string;
+        assert_eq!(shared.as_bytes(), [65, 66, 67]);
+        assert_eq!(shared.as_bytes(), shared.as_ascii_str().as_bytes());
+    }
+
+    #[test]
+    fn test_into_bytes_no_copy() {
+        use crate::ascii_string::AsciiString;
+        let mut string = AsciiString::with_capacity(16);
+        string += "ABC";
+        let ptr = string.as_bytes().as_ptr();
+        let bytes = string.into_bytes();
+        assert_eq!(bytes.as_ptr(), ptr);
+        let string = AsciiString::from(bytes);
+        assert_eq!(string.as_bytes().as_ptr(), ptr);
+    }
+
     #[test]
     fn test_iter_mut() {
         use crate::ascii_string::AsciiString;
@@ -1,4 +1,7 @@
+use crate::ascii_error::AsciiError;
+use crate::ascii_string::AsciiString;
 use crate::ascii_translators::*;
+use std::borrow::Cow;
 
 pub trait AsciiOrdToChar {
     /// Returns the character represented by the ASCII ordinal value.
@@ -36,15 +39,8 @@ pub trait CharToAsciiOrd {
     }
     /// Returns the ASCII ordinal value of a character in a Result.
     /// <br>
-    /// use ascii_ord() instead if you don't need the error message.
-    #[inline(always)]
-    fn try_ascii_ord(&self) -> Result<u8, String>
-    where
-        Self: std::fmt::Display,
-    {
-        self.ascii_ord()
-            .ok_or(format!("Character {self} is not ASCII"))
-    }
+    /// use ascii_ord() instead if you don't need the error details.
+    fn try_ascii_ord(&self) -> Result<u8, AsciiError>;
 }
 
 impl CharToAsciiOrd for char {
@@ -52,6 +48,10 @@ impl CharToAsciiOrd for char {
     fn ascii_ord(&self) -> Option<u8> {
         char_to_ascii_ord(*self)
     }
+    #[inline(always)]
+    fn try_ascii_ord(&self) -> Result<u8, AsciiError> {
+        self.ascii_ord().ok_or(AsciiError::non_ascii(*self, 0, 0))
+    }
 }
 
 impl CharToAsciiOrd for &char {
@@ -59,6 +59,10 @@ impl CharToAsciiOrd for &char {
     fn ascii_ord(&self) -> Option<u8> {
         char_ref_to_ascii_ord(self)
     }
+    #[inline(always)]
+    fn try_ascii_ord(&self) -> Result<u8, AsciiError> {
+        self.ascii_ord().ok_or(AsciiError::non_ascii(**self, 0, 0))
+    }
 }
 
 impl CharToAsciiOrd for u8 {
@@ -66,4 +70,94 @@ impl CharToAsciiOrd for u8 {
     fn ascii_ord(&self) -> Option<u8> {
         Some(*self)
     }
+    #[inline(always)]
+    fn try_ascii_ord(&self) -> Result<u8, AsciiError> {
+        Ok(*self)
+    }
+}
+
+/// A value whose ASCII bytes can be written into an `AsciiString` (see `AsciiString::insert_str` and `AsciiStr::replace`).
+/// <br>
+/// Implemented for the same mix of types accepted by `Add`/`AddAssign`:
+/// u8, char, `AsciiChar`, &str, `String`, `AsciiString` and `&AsciiStr` (plus byte slices).
+pub trait ToAsciiBytes {
+    /// Returns the ASCII bytes of the value, or an error if it contains non ASCII/Extended ASCII characters.
+    /// * values that are already ASCII bytes are borrowed, not copied.
+    fn try_ascii_bytes(&self) -> Result<Cow<'_, [u8]>, AsciiError>;
+    /// This is a convenience wrapper function that calls unwrap on try_ascii_bytes().
+    /// <br>
+    /// It will panic if the value contains non ASCII/Extended ASCII characters.
+    #[inline(always)]
+    fn ascii_bytes_unchecked(&self) -> Cow<'_, [u8]> {
+        self.try_ascii_bytes().unwrap()
+    }
+}
+
+impl ToAsciiBytes for u8 {
+    #[inline(always)]
+    fn try_ascii_bytes(&self) -> Result<Cow<'_, [u8]>, AsciiError> {
+        Ok(Cow::Borrowed(std::slice::from_ref(self)))
+    }
+}
+
+/// Every byte value, so a single byte can be borrowed as a one byte slice instead of allocated.
+pub(crate) static BYTES: [u8; 256] = {
+    let mut bytes = [0u8; 256];
+    let mut inx = 0;
+    while inx < 256 {
+        bytes[inx] = inx as u8;
+        inx += 1;
+    }
+    bytes
+};
+
+impl ToAsciiBytes for char {
+    #[inline(always)]
+    fn try_ascii_bytes(&self) -> Result<Cow<'_, [u8]>, AsciiError> {
+        let byte = self.try_ascii_ord()?;
+        Ok(Cow::Borrowed(std::slice::from_ref(&BYTES[byte as usize])))
+    }
+}
+
+impl ToAsciiBytes for &char {
+    #[inline(always)]
+    fn try_ascii_bytes(&self) -> Result<Cow<'_, [u8]>, AsciiError> {
+        (*self).try_ascii_bytes()
+    }
+}
+
+#[inline]
+fn str_to_ascii_bytes(string: &str) -> Result<Cow<'_, [u8]>, AsciiError> {
+    if string.is_ascii() {
+        return Ok(Cow::Borrowed(string.as_bytes()));
+    }
+    Ok(Cow::Owned(AsciiString::try_from(string)?.into_bytes()))
+}
+
+impl ToAsciiBytes for &str {
+    #[inline(always)]
+    fn try_ascii_bytes(&self) -> Result<Cow<'_, [u8]>, AsciiError> {
+        str_to_ascii_bytes(self)
+    }
+}
+
+impl ToAsciiBytes for String {
+    #[inline(always)]
+    fn try_ascii_bytes(&self) -> Result<Cow<'_, [u8]>, AsciiError> {
+        str_to_ascii_bytes(self)
+    }
+}
+
+impl ToAsciiBytes for &String {
+    #[inline(always)]
+    fn try_ascii_bytes(&self) -> Result<Cow<'_, [u8]>, AsciiError> {
+        str_to_ascii_bytes(self)
+    }
+}
+
+impl ToAsciiBytes for &[u8] {
+    #[inline(always)]
+    fn try_ascii_bytes(&self) -> Result<Cow<'_, [u8]>, AsciiError> {
+        Ok(Cow::Borrowed(self))
+    }
 }
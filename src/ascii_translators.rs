@@ -1,4 +1,6 @@
 use crate::ascii_consts::*;
+use crate::ascii_error::AsciiError;
+use std::fmt;
 
 /// Returns the character represented by the ASCII ordinal value.
 #[inline(always)]
@@ -282,6 +284,84 @@ pub const fn char_ref_to_ascii_ord(c: &char) -> Option<u8> {
     char_to_ascii_ord(*c)
 }
 
+/// Block size of the 7-bit ASCII fast paths.
+const CHUNK_LEN: usize = 16;
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+/// Returns true if the 16 byte chunk is all 7-bit ASCII, checked as two u64 words.
+#[inline(always)]
+fn is_ascii_chunk(chunk: &[u8]) -> bool {
+    let (low, high) = chunk.split_at(CHUNK_LEN / 2);
+    let low = u64::from_ne_bytes(low.try_into().expect("8 bytes"));
+    let high = u64::from_ne_bytes(high.try_into().expect("8 bytes"));
+    (low | high) & HIGH_BITS == 0
+}
+
+/// Transcodes a UTF-8 string to ASCII/Extended ASCII (Latin-1) bytes, appending them to `out`.
+/// <br>
+/// The input is processed in 16 byte blocks: all 7-bit ASCII blocks are copied as is, other blocks
+/// are decoded a byte at a time. U+0080..=U+00FF are the two byte UTF-8 sequences with a 0xC2 or 0xC3
+/// lead byte, which are decoded directly without going through `char`.
+/// # Errors
+/// * if the string contains a character above U+00FF. `out` keeps the bytes transcoded before it.
+pub(crate) fn utf8_to_latin1(string: &str, out: &mut Vec<u8>) -> Result<(), AsciiError> {
+    // the output is never longer than the input.
+    out.reserve(string.len());
+    let mut rest = string.as_bytes();
+    loop {
+        if let Some(chunk) = rest.get(..CHUNK_LEN) {
+            if is_ascii_chunk(chunk) {
+                out.extend_from_slice(chunk);
+                rest = &rest[CHUNK_LEN..];
+                continue;
+            }
+        }
+        for _ in 0..CHUNK_LEN {
+            rest = match rest {
+                [byte @ 0x00..=0x7F, tail @ ..] => {
+                    out.push(*byte);
+                    tail
+                }
+                [lead @ (0xC2 | 0xC3), continuation, tail @ ..] => {
+                    out.push(((lead & 0x03) << 6) | (continuation & 0x3F));
+                    tail
+                }
+                [] => return Ok(()),
+                _ => {
+                    let byte_index = string.len() - rest.len();
+                    let character = string[byte_index..].chars().next().expect("char at index");
+                    let char_index = string[..byte_index].chars().count();
+                    return Err(AsciiError::non_ascii(character, char_index, byte_index));
+                }
+            };
+        }
+    }
+}
+
+/// Writes ASCII/Extended ASCII (Latin-1) bytes as UTF-8 (used by `Display` and `String::from`).
+/// <br>
+/// The input is processed in 16 byte blocks: all 7-bit ASCII blocks are written as is, other blocks
+/// are encoded into a small buffer first, so there is one `write_str` call per block.
+pub(crate) fn write_latin1<W: fmt::Write>(out: &mut W, bytes: &[u8]) -> fmt::Result {
+    let mut buffer = [0u8; CHUNK_LEN * 2];
+    for chunk in bytes.chunks(CHUNK_LEN) {
+        if chunk.len() == CHUNK_LEN && is_ascii_chunk(chunk) {
+            // SAFETY: 7-bit ASCII is valid UTF-8.
+            out.write_str(unsafe { std::str::from_utf8_unchecked(chunk) })?;
+            continue;
+        }
+        let mut len = 0;
+        for byte in chunk {
+            len += ascii_ord_to_char(*byte)
+                .encode_utf8(&mut buffer[len..])
+                .len();
+        }
+        // SAFETY: the buffer only holds whole chars encoded by encode_utf8.
+        out.write_str(unsafe { std::str::from_utf8_unchecked(&buffer[..len]) })?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -719,4 +799,42 @@ mod test {
             '\u{00FF}'
         );
     }
+
+    /// The char by char conversion `TryFrom<&str>` used before the fast path.
+    fn reference_to_latin1(string: &str) -> Result<Vec<u8>, AsciiError> {
+        let mut result = Vec::new();
+        for (char_index, (byte_index, character)) in string.char_indices().enumerate() {
+            match char_to_ascii_ord(character) {
+                Some(byte) => result.push(byte),
+                None => return Err(AsciiError::non_ascii(character, char_index, byte_index)),
+            }
+        }
+        Ok(result)
+    }
+
+    #[test]
+    fn test_utf8_to_latin1() {
+        let all: String = (0..=255u8).map(char::from).collect();
+        let long_ascii = "0123456789abcdef".repeat(5);
+        let samples = [
+            String::new(),
+            all.clone(),
+            all.chars().rev().collect(),
+            format!("{long_ascii}é{long_ascii}ÿÿ{long_ascii}"),
+            format!("{long_ascii}€"),
+            format!("é{long_ascii}ñ𝄞"),
+            "ab€cd".to_string(),
+            "ÀÁÂ—".to_string(),
+        ];
+        for sample in samples {
+            let mut out = Vec::new();
+            let result = utf8_to_latin1(&sample, &mut out).map(|_| out);
+            assert_eq!(result, reference_to_latin1(&sample), "{sample}");
+            if let Ok(latin1) = result {
+                let mut string = String::new();
+                write_latin1(&mut string, &latin1).unwrap();
+                assert_eq!(string, sample);
+            }
+        }
+    }
 }
@@ -0,0 +1,913 @@
+use crate::ascii_string::AsciiString;
+use crate::ascii_traits::BYTES;
+
+/// The character set transliterated text is limited to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TransliterateTarget {
+    /// Only 7-bit ASCII (0x00..=0x7F); Latin-1 characters such as `é` or `£` are transliterated too.
+    #[default]
+    Ascii7,
+    /// The full Extended ASCII (Latin-1) range; only characters above U+00FF are transliterated.
+    Latin1,
+}
+
+/// Options for transliterating Unicode text into an `AsciiString`
+/// (see `AsciiString::push_str_transliterate` and `AsciiString::from_str_transliterated`).
+/// <br>
+/// Characters outside the target are replaced with their closest ASCII sequence:
+/// * smart quotes, dashes and ellipses (`“` -> `"`, `—` -> `--`, `…` -> `...`).
+/// * ligatures (`œ` -> `oe`, `ﬁ` -> `fi`).
+/// * accented Latin letters (`ł` -> `l`, `ő` -> `o`, `ệ` -> `e`), precomposed or decomposed (NFD):
+///   combining marks (U+0300..=U+036F) are dropped.
+/// * currency and common symbols (`€` -> `EUR`, `™` -> `(TM)`, `→` -> `->`).
+/// * fullwidth forms (`Ａ` -> `A`).
+///
+/// Characters without a transliteration are replaced with the replacement byte (`?` by default), or skipped.
+/// # Examples
+/// ```
+/// # use cj_ascii::prelude::*;
+/// let text = "“Zürich” – café œuvre €5";
+/// let astring = AsciiString::from_str_transliterated(text, Transliteration::default());
+/// assert_eq!(astring.to_string(), "\"Zurich\" - cafe oeuvre EUR5");
+///
+/// let latin1 = Transliteration::new(TransliterateTarget::Latin1);
+/// let astring = AsciiString::from_str_transliterated(text, latin1);
+/// assert_eq!(astring.to_string(), "\"Zürich\" - café oeuvre EUR5");
+///
+/// let skip = Transliteration::default().skip_unmappable();
+/// assert_eq!(AsciiString::from_str_transliterated("Ω1", skip).to_string(), "1");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Transliteration {
+    target: TransliterateTarget,
+    replacement: Option<u8>,
+}
+
+impl Default for Transliteration {
+    fn default() -> Self {
+        Self::new(TransliterateTarget::Ascii7)
+    }
+}
+
+impl Transliteration {
+    /// Creates a new Transliteration for the target, replacing unmappable characters with `?`.
+    pub const fn new(target: TransliterateTarget) -> Self {
+        Self {
+            target,
+            replacement: Some(b'?'),
+        }
+    }
+    /// Replaces unmappable characters with the specified byte.
+    /// # Panics
+    /// * if the target is `Ascii7` and the byte is Extended ASCII (0x80..=0xFF), as the output would no longer be 7-bit.
+    pub const fn with_replacement(self, replacement: u8) -> Self {
+        assert!(
+            replacement < 0x80 || matches!(self.target, TransliterateTarget::Latin1),
+            "replacement must be 7-bit ASCII for the Ascii7 target"
+        );
+        Self {
+            replacement: Some(replacement),
+            ..self
+        }
+    }
+    /// Skips (drops) unmappable characters.
+    pub const fn skip_unmappable(self) -> Self {
+        Self {
+            replacement: None,
+            ..self
+        }
+    }
+    /// Returns the target character set.
+    pub const fn target(&self) -> TransliterateTarget {
+        self.target
+    }
+    /// Returns the byte unmappable characters are replaced with, or `None` if they are skipped.
+    pub const fn replacement(&self) -> Option<u8> {
+        self.replacement
+    }
+    /// Returns the transliteration of a single character, or `None` if it has none.
+    /// * characters already inside the target are returned as is.
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let ascii7 = Transliteration::new(TransliterateTarget::Ascii7);
+    /// assert_eq!(ascii7.transliterate('A'), Some(&b"A"[..]));
+    /// assert_eq!(ascii7.transliterate('Æ'), Some(&b"AE"[..]));
+    /// assert_eq!(ascii7.transliterate('Ω'), None);
+    /// let latin1 = Transliteration::new(TransliterateTarget::Latin1);
+    /// assert_eq!(latin1.transliterate('Æ'), Some(&[0xC6][..]));
+    /// ```
+    pub fn transliterate(&self, character: char) -> Option<&'static [u8]> {
+        let code = character as u32;
+        if code < 0x80 || (code <= 0xFF && self.target == TransliterateTarget::Latin1) {
+            return Some(&BYTES[code as usize..=code as usize]);
+        }
+        // fullwidth forms mirror the printable ASCII characters.
+        if (0xFF01..=0xFF5E).contains(&code) {
+            let byte = (code - 0xFEE0) as usize;
+            return Some(&BYTES[byte..=byte]);
+        }
+        // combining diacritical marks (decomposed text); the base letter has already been pushed.
+        if (0x0300..=0x036F).contains(&code) {
+            return Some(&[]);
+        }
+        TRANSLITERATIONS
+            .binary_search_by_key(&character, |(c, _)| *c)
+            .ok()
+            .map(|inx| TRANSLITERATIONS[inx].1.as_bytes())
+    }
+    #[inline]
+    fn push_char(&self, bytes: &mut Vec<u8>, character: char) {
+        match self.transliterate(character) {
+            Some(transliterated) => bytes.extend_from_slice(transliterated),
+            None => bytes.extend(self.replacement),
+        }
+    }
+}
+
+impl AsciiString {
+    /// Pushes a string onto the end of the `AsciiString`, transliterating characters outside the target
+    /// to their closest ASCII sequence (see `Transliteration`).
+    /// # Examples
+    /// ```
+    /// # use cj_ascii::prelude::*;
+    /// let mut astring = AsciiString::new();
+    /// astring.push_str_transliterate("Łódź — ½ ✓", Transliteration::default());
+    /// astring.push_str_transliterate(" Ωmega", Transliteration::default().with_replacement(b'_'));
+    /// assert_eq!(astring.to_string(), "Lodz -- 1/2 v _mega");
+    /// ```
+    pub fn push_str_transliterate(&mut self, string: &str, transliteration: Transliteration) {
+        self.bytes.reserve(string.len());
+        for character in string.chars() {
+            transliteration.push_char(&mut self.bytes, character);
+        }
+    }
+    /// Creates an `AsciiString` from a string, transliterating characters outside the target
+    /// to their closest ASCII sequence (see `Transliteration`).
+    pub fn from_str_transliterated(string: &str, transliteration: Transliteration) -> AsciiString {
+        let mut astring = AsciiString::with_capacity(string.len());
+        astring.push_str_transliterate(string, transliteration);
+        astring
+    }
+}
+
+/// Transliterations of characters outside 7-bit ASCII, sorted by char for binary search.
+/// <br>
+/// Accented letters are their NFKD decomposition without combining marks;
+/// punctuation, ligatures and symbols follow common usage (e.g. `€` -> `EUR`).
+static TRANSLITERATIONS: [(char, &str); 677] = [
+    ('\u{00A0}', " "),
+    ('\u{00A1}', "!"),
+    ('\u{00A2}', "c"),
+    ('\u{00A3}', "GBP"),
+    ('\u{00A4}', "$"),
+    ('\u{00A5}', "JPY"),
+    ('\u{00A6}', "|"),
+    ('\u{00A7}', "SS"),
+    ('\u{00A8}', "\""),
+    ('\u{00A9}', "(C)"),
+    ('\u{00AA}', "a"),
+    ('\u{00AB}', "<<"),
+    ('\u{00AC}', "!"),
+    ('\u{00AD}', ""),
+    ('\u{00AE}', "(R)"),
+    ('\u{00AF}', "-"),
+    ('\u{00B0}', "deg"),
+    ('\u{00B1}', "+/-"),
+    ('\u{00B2}', "2"),
+    ('\u{00B3}', "3"),
+    ('\u{00B4}', "'"),
+    ('\u{00B5}', "u"),
+    ('\u{00B6}', "P"),
+    ('\u{00B7}', "."),
+    ('\u{00B8}', ","),
+    ('\u{00B9}', "1"),
+    ('\u{00BA}', "o"),
+    ('\u{00BB}', ">>"),
+    ('\u{00BC}', "1/4"),
+    ('\u{00BD}', "1/2"),
+    ('\u{00BE}', "3/4"),
+    ('\u{00BF}', "?"),
+    ('\u{00C0}', "A"),
+    ('\u{00C1}', "A"),
+    ('\u{00C2}', "A"),
+    ('\u{00C3}', "A"),
+    ('\u{00C4}', "A"),
+    ('\u{00C5}', "A"),
+    ('\u{00C6}', "AE"),
+    ('\u{00C7}', "C"),
+    ('\u{00C8}', "E"),
+    ('\u{00C9}', "E"),
+    ('\u{00CA}', "E"),
+    ('\u{00CB}', "E"),
+    ('\u{00CC}', "I"),
+    ('\u{00CD}', "I"),
+    ('\u{00CE}', "I"),
+    ('\u{00CF}', "I"),
+    ('\u{00D0}', "D"),
+    ('\u{00D1}', "N"),
+    ('\u{00D2}', "O"),
+    ('\u{00D3}', "O"),
+    ('\u{00D4}', "O"),
+    ('\u{00D5}', "O"),
+    ('\u{00D6}', "O"),
+    ('\u{00D7}', "x"),
+    ('\u{00D8}', "O"),
+    ('\u{00D9}', "U"),
+    ('\u{00DA}', "U"),
+    ('\u{00DB}', "U"),
+    ('\u{00DC}', "U"),
+    ('\u{00DD}', "Y"),
+    ('\u{00DE}', "TH"),
+    ('\u{00DF}', "ss"),
+    ('\u{00E0}', "a"),
+    ('\u{00E1}', "a"),
+    ('\u{00E2}', "a"),
+    ('\u{00E3}', "a"),
+    ('\u{00E4}', "a"),
+    ('\u{00E5}', "a"),
+    ('\u{00E6}', "ae"),
+    ('\u{00E7}', "c"),
+    ('\u{00E8}', "e"),
+    ('\u{00E9}', "e"),
+    ('\u{00EA}', "e"),
+    ('\u{00EB}', "e"),
+    ('\u{00EC}', "i"),
+    ('\u{00ED}', "i"),
+    ('\u{00EE}', "i"),
+    ('\u{00EF}', "i"),
+    ('\u{00F0}', "d"),
+    ('\u{00F1}', "n"),
+    ('\u{00F2}', "o"),
+    ('\u{00F3}', "o"),
+    ('\u{00F4}', "o"),
+    ('\u{00F5}', "o"),
+    ('\u{00F6}', "o"),
+    ('\u{00F7}', "/"),
+    ('\u{00F8}', "o"),
+    ('\u{00F9}', "u"),
+    ('\u{00FA}', "u"),
+    ('\u{00FB}', "u"),
+    ('\u{00FC}', "u"),
+    ('\u{00FD}', "y"),
+    ('\u{00FE}', "th"),
+    ('\u{00FF}', "y"),
+    ('\u{0100}', "A"),
+    ('\u{0101}', "a"),
+    ('\u{0102}', "A"),
+    ('\u{0103}', "a"),
+    ('\u{0104}', "A"),
+    ('\u{0105}', "a"),
+    ('\u{0106}', "C"),
+    ('\u{0107}', "c"),
+    ('\u{0108}', "C"),
+    ('\u{0109}', "c"),
+    ('\u{010A}', "C"),
+    ('\u{010B}', "c"),
+    ('\u{010C}', "C"),
+    ('\u{010D}', "c"),
+    ('\u{010E}', "D"),
+    ('\u{010F}', "d"),
+    ('\u{0110}', "D"),
+    ('\u{0111}', "d"),
+    ('\u{0112}', "E"),
+    ('\u{0113}', "e"),
+    ('\u{0114}', "E"),
+    ('\u{0115}', "e"),
+    ('\u{0116}', "E"),
+    ('\u{0117}', "e"),
+    ('\u{0118}', "E"),
+    ('\u{0119}', "e"),
+    ('\u{011A}', "E"),
+    ('\u{011B}', "e"),
+    ('\u{011C}', "G"),
+    ('\u{011D}', "g"),
+    ('\u{011E}', "G"),
+    ('\u{011F}', "g"),
+    ('\u{0120}', "G"),
+    ('\u{0121}', "g"),
+    ('\u{0122}', "G"),
+    ('\u{0123}', "g"),
+    ('\u{0124}', "H"),
+    ('\u{0125}', "h"),
+    ('\u{0126}', "H"),
+    ('\u{0127}', "h"),
+    ('\u{0128}', "I"),
+    ('\u{0129}', "i"),
+    ('\u{012A}', "I"),
+    ('\u{012B}', "i"),
+    ('\u{012C}', "I"),
+    ('\u{012D}', "i"),
+    ('\u{012E}', "I"),
+    ('\u{012F}', "i"),
+    ('\u{0130}', "I"),
+    ('\u{0131}', "i"),
+    ('\u{0132}', "IJ"),
+    ('\u{0133}', "ij"),
+    ('\u{0134}', "J"),
+    ('\u{0135}', "j"),
+    ('\u{0136}', "K"),
+    ('\u{0137}', "k"),
+    ('\u{0138}', "q"),
+    ('\u{0139}', "L"),
+    ('\u{013A}', "l"),
+    ('\u{013B}', "L"),
+    ('\u{013C}', "l"),
+    ('\u{013D}', "L"),
+    ('\u{013E}', "l"),
+    ('\u{013F}', "L"),
+    ('\u{0140}', "l"),
+    ('\u{0141}', "L"),
+    ('\u{0142}', "l"),
+    ('\u{0143}', "N"),
+    ('\u{0144}', "n"),
+    ('\u{0145}', "N"),
+    ('\u{0146}', "n"),
+    ('\u{0147}', "N"),
+    ('\u{0148}', "n"),
+    ('\u{0149}', "'n"),
+    ('\u{014A}', "NG"),
+    ('\u{014B}', "ng"),
+    ('\u{014C}', "O"),
+    ('\u{014D}', "o"),
+    ('\u{014E}', "O"),
+    ('\u{014F}', "o"),
+    ('\u{0150}', "O"),
+    ('\u{0151}', "o"),
+    ('\u{0152}', "OE"),
+    ('\u{0153}', "oe"),
+    ('\u{0154}', "R"),
+    ('\u{0155}', "r"),
+    ('\u{0156}', "R"),
+    ('\u{0157}', "r"),
+    ('\u{0158}', "R"),
+    ('\u{0159}', "r"),
+    ('\u{015A}', "S"),
+    ('\u{015B}', "s"),
+    ('\u{015C}', "S"),
+    ('\u{015D}', "s"),
+    ('\u{015E}', "S"),
+    ('\u{015F}', "s"),
+    ('\u{0160}', "S"),
+    ('\u{0161}', "s"),
+    ('\u{0162}', "T"),
+    ('\u{0163}', "t"),
+    ('\u{0164}', "T"),
+    ('\u{0165}', "t"),
+    ('\u{0166}', "T"),
+    ('\u{0167}', "t"),
+    ('\u{0168}', "U"),
+    ('\u{0169}', "u"),
+    ('\u{016A}', "U"),
+    ('\u{016B}', "u"),
+    ('\u{016C}', "U"),
+    ('\u{016D}', "u"),
+    ('\u{016E}', "U"),
+    ('\u{016F}', "u"),
+    ('\u{0170}', "U"),
+    ('\u{0171}', "u"),
+    ('\u{0172}', "U"),
+    ('\u{0173}', "u"),
+    ('\u{0174}', "W"),
+    ('\u{0175}', "w"),
+    ('\u{0176}', "Y"),
+    ('\u{0177}', "y"),
+    ('\u{0178}', "Y"),
+    ('\u{0179}', "Z"),
+    ('\u{017A}', "z"),
+    ('\u{017B}', "Z"),
+    ('\u{017C}', "z"),
+    ('\u{017D}', "Z"),
+    ('\u{017E}', "z"),
+    ('\u{017F}', "s"),
+    ('\u{018F}', "E"),
+    ('\u{0192}', "f"),
+    ('\u{01A0}', "O"),
+    ('\u{01A1}', "o"),
+    ('\u{01AF}', "U"),
+    ('\u{01B0}', "u"),
+    ('\u{01C4}', "DZ"),
+    ('\u{01C5}', "Dz"),
+    ('\u{01C6}', "dz"),
+    ('\u{01C7}', "LJ"),
+    ('\u{01C8}', "Lj"),
+    ('\u{01C9}', "lj"),
+    ('\u{01CA}', "NJ"),
+    ('\u{01CB}', "Nj"),
+    ('\u{01CC}', "nj"),
+    ('\u{01CD}', "A"),
+    ('\u{01CE}', "a"),
+    ('\u{01CF}', "I"),
+    ('\u{01D0}', "i"),
+    ('\u{01D1}', "O"),
+    ('\u{01D2}', "o"),
+    ('\u{01D3}', "U"),
+    ('\u{01D4}', "u"),
+    ('\u{01D5}', "U"),
+    ('\u{01D6}', "u"),
+    ('\u{01D7}', "U"),
+    ('\u{01D8}', "u"),
+    ('\u{01D9}', "U"),
+    ('\u{01DA}', "u"),
+    ('\u{01DB}', "U"),
+    ('\u{01DC}', "u"),
+    ('\u{01DE}', "A"),
+    ('\u{01DF}', "a"),
+    ('\u{01E0}', "A"),
+    ('\u{01E1}', "a"),
+    ('\u{01E6}', "G"),
+    ('\u{01E7}', "g"),
+    ('\u{01E8}', "K"),
+    ('\u{01E9}', "k"),
+    ('\u{01EA}', "O"),
+    ('\u{01EB}', "o"),
+    ('\u{01EC}', "O"),
+    ('\u{01ED}', "o"),
+    ('\u{01F0}', "j"),
+    ('\u{01F1}', "DZ"),
+    ('\u{01F2}', "Dz"),
+    ('\u{01F3}', "dz"),
+    ('\u{01F4}', "G"),
+    ('\u{01F5}', "g"),
+    ('\u{01F8}', "N"),
+    ('\u{01F9}', "n"),
+    ('\u{01FA}', "A"),
+    ('\u{01FB}', "a"),
+    ('\u{0200}', "A"),
+    ('\u{0201}', "a"),
+    ('\u{0202}', "A"),
+    ('\u{0203}', "a"),
+    ('\u{0204}', "E"),
+    ('\u{0205}', "e"),
+    ('\u{0206}', "E"),
+    ('\u{0207}', "e"),
+    ('\u{0208}', "I"),
+    ('\u{0209}', "i"),
+    ('\u{020A}', "I"),
+    ('\u{020B}', "i"),
+    ('\u{020C}', "O"),
+    ('\u{020D}', "o"),
+    ('\u{020E}', "O"),
+    ('\u{020F}', "o"),
+    ('\u{0210}', "R"),
+    ('\u{0211}', "r"),
+    ('\u{0212}', "R"),
+    ('\u{0213}', "r"),
+    ('\u{0214}', "U"),
+    ('\u{0215}', "u"),
+    ('\u{0216}', "U"),
+    ('\u{0217}', "u"),
+    ('\u{0218}', "S"),
+    ('\u{0219}', "s"),
+    ('\u{021A}', "T"),
+    ('\u{021B}', "t"),
+    ('\u{021E}', "H"),
+    ('\u{021F}', "h"),
+    ('\u{0226}', "A"),
+    ('\u{0227}', "a"),
+    ('\u{0228}', "E"),
+    ('\u{0229}', "e"),
+    ('\u{022A}', "O"),
+    ('\u{022B}', "o"),
+    ('\u{022C}', "O"),
+    ('\u{022D}', "o"),
+    ('\u{022E}', "O"),
+    ('\u{022F}', "o"),
+    ('\u{0230}', "O"),
+    ('\u{0231}', "o"),
+    ('\u{0232}', "Y"),
+    ('\u{0233}', "y"),
+    ('\u{0259}', "e"),
+    ('\u{1E00}', "A"),
+    ('\u{1E01}', "a"),
+    ('\u{1E02}', "B"),
+    ('\u{1E03}', "b"),
+    ('\u{1E04}', "B"),
+    ('\u{1E05}', "b"),
+    ('\u{1E06}', "B"),
+    ('\u{1E07}', "b"),
+    ('\u{1E08}', "C"),
+    ('\u{1E09}', "c"),
+    ('\u{1E0A}', "D"),
+    ('\u{1E0B}', "d"),
+    ('\u{1E0C}', "D"),
+    ('\u{1E0D}', "d"),
+    ('\u{1E0E}', "D"),
+    ('\u{1E0F}', "d"),
+    ('\u{1E10}', "D"),
+    ('\u{1E11}', "d"),
+    ('\u{1E12}', "D"),
+    ('\u{1E13}', "d"),
+    ('\u{1E14}', "E"),
+    ('\u{1E15}', "e"),
+    ('\u{1E16}', "E"),
+    ('\u{1E17}', "e"),
+    ('\u{1E18}', "E"),
+    ('\u{1E19}', "e"),
+    ('\u{1E1A}', "E"),
+    ('\u{1E1B}', "e"),
+    ('\u{1E1C}', "E"),
+    ('\u{1E1D}', "e"),
+    ('\u{1E1E}', "F"),
+    ('\u{1E1F}', "f"),
+    ('\u{1E20}', "G"),
+    ('\u{1E21}', "g"),
+    ('\u{1E22}', "H"),
+    ('\u{1E23}', "h"),
+    ('\u{1E24}', "H"),
+    ('\u{1E25}', "h"),
+    ('\u{1E26}', "H"),
+    ('\u{1E27}', "h"),
+    ('\u{1E28}', "H"),
+    ('\u{1E29}', "h"),
+    ('\u{1E2A}', "H"),
+    ('\u{1E2B}', "h"),
+    ('\u{1E2C}', "I"),
+    ('\u{1E2D}', "i"),
+    ('\u{1E2E}', "I"),
+    ('\u{1E2F}', "i"),
+    ('\u{1E30}', "K"),
+    ('\u{1E31}', "k"),
+    ('\u{1E32}', "K"),
+    ('\u{1E33}', "k"),
+    ('\u{1E34}', "K"),
+    ('\u{1E35}', "k"),
+    ('\u{1E36}', "L"),
+    ('\u{1E37}', "l"),
+    ('\u{1E38}', "L"),
+    ('\u{1E39}', "l"),
+    ('\u{1E3A}', "L"),
+    ('\u{1E3B}', "l"),
+    ('\u{1E3C}', "L"),
+    ('\u{1E3D}', "l"),
+    ('\u{1E3E}', "M"),
+    ('\u{1E3F}', "m"),
+    ('\u{1E40}', "M"),
+    ('\u{1E41}', "m"),
+    ('\u{1E42}', "M"),
+    ('\u{1E43}', "m"),
+    ('\u{1E44}', "N"),
+    ('\u{1E45}', "n"),
+    ('\u{1E46}', "N"),
+    ('\u{1E47}', "n"),
+    ('\u{1E48}', "N"),
+    ('\u{1E49}', "n"),
+    ('\u{1E4A}', "N"),
+    ('\u{1E4B}', "n"),
+    ('\u{1E4C}', "O"),
+    ('\u{1E4D}', "o"),
+    ('\u{1E4E}', "O"),
+    ('\u{1E4F}', "o"),
+    ('\u{1E50}', "O"),
+    ('\u{1E51}', "o"),
+    ('\u{1E52}', "O"),
+    ('\u{1E53}', "o"),
+    ('\u{1E54}', "P"),
+    ('\u{1E55}', "p"),
+    ('\u{1E56}', "P"),
+    ('\u{1E57}', "p"),
+    ('\u{1E58}', "R"),
+    ('\u{1E59}', "r"),
+    ('\u{1E5A}', "R"),
+    ('\u{1E5B}', "r"),
+    ('\u{1E5C}', "R"),
+    ('\u{1E5D}', "r"),
+    ('\u{1E5E}', "R"),
+    ('\u{1E5F}', "r"),
+    ('\u{1E60}', "S"),
+    ('\u{1E61}', "s"),
+    ('\u{1E62}', "S"),
+    ('\u{1E63}', "s"),
+    ('\u{1E64}', "S"),
+    ('\u{1E65}', "s"),
+    ('\u{1E66}', "S"),
+    ('\u{1E67}', "s"),
+    ('\u{1E68}', "S"),
+    ('\u{1E69}', "s"),
+    ('\u{1E6A}', "T"),
+    ('\u{1E6B}', "t"),
+    ('\u{1E6C}', "T"),
+    ('\u{1E6D}', "t"),
+    ('\u{1E6E}', "T"),
+    ('\u{1E6F}', "t"),
+    ('\u{1E70}', "T"),
+    ('\u{1E71}', "t"),
+    ('\u{1E72}', "U"),
+    ('\u{1E73}', "u"),
+    ('\u{1E74}', "U"),
+    ('\u{1E75}', "u"),
+    ('\u{1E76}', "U"),
+    ('\u{1E77}', "u"),
+    ('\u{1E78}', "U"),
+    ('\u{1E79}', "u"),
+    ('\u{1E7A}', "U"),
+    ('\u{1E7B}', "u"),
+    ('\u{1E7C}', "V"),
+    ('\u{1E7D}', "v"),
+    ('\u{1E7E}', "V"),
+    ('\u{1E7F}', "v"),
+    ('\u{1E80}', "W"),
+    ('\u{1E81}', "w"),
+    ('\u{1E82}', "W"),
+    ('\u{1E83}', "w"),
+    ('\u{1E84}', "W"),
+    ('\u{1E85}', "w"),
+    ('\u{1E86}', "W"),
+    ('\u{1E87}', "w"),
+    ('\u{1E88}', "W"),
+    ('\u{1E89}', "w"),
+    ('\u{1E8A}', "X"),
+    ('\u{1E8B}', "x"),
+    ('\u{1E8C}', "X"),
+    ('\u{1E8D}', "x"),
+    ('\u{1E8E}', "Y"),
+    ('\u{1E8F}', "y"),
+    ('\u{1E90}', "Z"),
+    ('\u{1E91}', "z"),
+    ('\u{1E92}', "Z"),
+    ('\u{1E93}', "z"),
+    ('\u{1E94}', "Z"),
+    ('\u{1E95}', "z"),
+    ('\u{1E96}', "h"),
+    ('\u{1E97}', "t"),
+    ('\u{1E98}', "w"),
+    ('\u{1E99}', "y"),
+    ('\u{1E9B}', "s"),
+    ('\u{1E9E}', "SS"),
+    ('\u{1EA0}', "A"),
+    ('\u{1EA1}', "a"),
+    ('\u{1EA2}', "A"),
+    ('\u{1EA3}', "a"),
+    ('\u{1EA4}', "A"),
+    ('\u{1EA5}', "a"),
+    ('\u{1EA6}', "A"),
+    ('\u{1EA7}', "a"),
+    ('\u{1EA8}', "A"),
+    ('\u{1EA9}', "a"),
+    ('\u{1EAA}', "A"),
+    ('\u{1EAB}', "a"),
+    ('\u{1EAC}', "A"),
+    ('\u{1EAD}', "a"),
+    ('\u{1EAE}', "A"),
+    ('\u{1EAF}', "a"),
+    ('\u{1EB0}', "A"),
+    ('\u{1EB1}', "a"),
+    ('\u{1EB2}', "A"),
+    ('\u{1EB3}', "a"),
+    ('\u{1EB4}', "A"),
+    ('\u{1EB5}', "a"),
+    ('\u{1EB6}', "A"),
+    ('\u{1EB7}', "a"),
+    ('\u{1EB8}', "E"),
+    ('\u{1EB9}', "e"),
+    ('\u{1EBA}', "E"),
+    ('\u{1EBB}', "e"),
+    ('\u{1EBC}', "E"),
+    ('\u{1EBD}', "e"),
+    ('\u{1EBE}', "E"),
+    ('\u{1EBF}', "e"),
+    ('\u{1EC0}', "E"),
+    ('\u{1EC1}', "e"),
+    ('\u{1EC2}', "E"),
+    ('\u{1EC3}', "e"),
+    ('\u{1EC4}', "E"),
+    ('\u{1EC5}', "e"),
+    ('\u{1EC6}', "E"),
+    ('\u{1EC7}', "e"),
+    ('\u{1EC8}', "I"),
+    ('\u{1EC9}', "i"),
+    ('\u{1ECA}', "I"),
+    ('\u{1ECB}', "i"),
+    ('\u{1ECC}', "O"),
+    ('\u{1ECD}', "o"),
+    ('\u{1ECE}', "O"),
+    ('\u{1ECF}', "o"),
+    ('\u{1ED0}', "O"),
+    ('\u{1ED1}', "o"),
+    ('\u{1ED2}', "O"),
+    ('\u{1ED3}', "o"),
+    ('\u{1ED4}', "O"),
+    ('\u{1ED5}', "o"),
+    ('\u{1ED6}', "O"),
+    ('\u{1ED7}', "o"),
+    ('\u{1ED8}', "O"),
+    ('\u{1ED9}', "o"),
+    ('\u{1EDA}', "O"),
+    ('\u{1EDB}', "o"),
+    ('\u{1EDC}', "O"),
+    ('\u{1EDD}', "o"),
+    ('\u{1EDE}', "O"),
+    ('\u{1EDF}', "o"),
+    ('\u{1EE0}', "O"),
+    ('\u{1EE1}', "o"),
+    ('\u{1EE2}', "O"),
+    ('\u{1EE3}', "o"),
+    ('\u{1EE4}', "U"),
+    ('\u{1EE5}', "u"),
+    ('\u{1EE6}', "U"),
+    ('\u{1EE7}', "u"),
+    ('\u{1EE8}', "U"),
+    ('\u{1EE9}', "u"),
+    ('\u{1EEA}', "U"),
+    ('\u{1EEB}', "u"),
+    ('\u{1EEC}', "U"),
+    ('\u{1EED}', "u"),
+    ('\u{1EEE}', "U"),
+    ('\u{1EEF}', "u"),
+    ('\u{1EF0}', "U"),
+    ('\u{1EF1}', "u"),
+    ('\u{1EF2}', "Y"),
+    ('\u{1EF3}', "y"),
+    ('\u{1EF4}', "Y"),
+    ('\u{1EF5}', "y"),
+    ('\u{1EF6}', "Y"),
+    ('\u{1EF7}', "y"),
+    ('\u{1EF8}', "Y"),
+    ('\u{1EF9}', "y"),
+    ('\u{2000}', " "),
+    ('\u{2001}', " "),
+    ('\u{2002}', " "),
+    ('\u{2003}', " "),
+    ('\u{2004}', " "),
+    ('\u{2005}', " "),
+    ('\u{2006}', " "),
+    ('\u{2007}', " "),
+    ('\u{2008}', " "),
+    ('\u{2009}', " "),
+    ('\u{200A}', " "),
+    ('\u{200B}', ""),
+    ('\u{200C}', ""),
+    ('\u{200D}', ""),
+    ('\u{2010}', "-"),
+    ('\u{2011}', "-"),
+    ('\u{2012}', "-"),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "--"),
+    ('\u{2015}', "--"),
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{201A}', "'"),
+    ('\u{201B}', "'"),
+    ('\u{201C}', "\""),
+    ('\u{201D}', "\""),
+    ('\u{201E}', "\""),
+    ('\u{201F}', "\""),
+    ('\u{2022}', "*"),
+    ('\u{2023}', ">"),
+    ('\u{2024}', "."),
+    ('\u{2025}', ".."),
+    ('\u{2026}', "..."),
+    ('\u{202F}', " "),
+    ('\u{2032}', "'"),
+    ('\u{2033}', "\""),
+    ('\u{2034}', "'''"),
+    ('\u{2039}', "<"),
+    ('\u{203A}', ">"),
+    ('\u{203C}', "!!"),
+    ('\u{2044}', "/"),
+    ('\u{2047}', "??"),
+    ('\u{2048}', "?!"),
+    ('\u{2049}', "!?"),
+    ('\u{205F}', " "),
+    ('\u{2060}', ""),
+    ('\u{20A1}', "CRC"),
+    ('\u{20A6}', "NGN"),
+    ('\u{20A9}', "KRW"),
+    ('\u{20AA}', "ILS"),
+    ('\u{20AB}', "VND"),
+    ('\u{20AC}', "EUR"),
+    ('\u{20B1}', "PHP"),
+    ('\u{20B2}', "PYG"),
+    ('\u{20B4}', "UAH"),
+    ('\u{20B5}', "GHS"),
+    ('\u{20B8}', "KZT"),
+    ('\u{20B9}', "INR"),
+    ('\u{20BA}', "TRY"),
+    ('\u{20BD}', "RUB"),
+    ('\u{20BF}', "BTC"),
+    ('\u{2103}', "C"),
+    ('\u{2109}', "F"),
+    ('\u{2116}', "No"),
+    ('\u{2117}', "(P)"),
+    ('\u{2120}', "(SM)"),
+    ('\u{2122}', "(TM)"),
+    ('\u{2153}', "1/3"),
+    ('\u{2154}', "2/3"),
+    ('\u{215B}', "1/8"),
+    ('\u{215C}', "3/8"),
+    ('\u{215D}', "5/8"),
+    ('\u{215E}', "7/8"),
+    ('\u{2190}', "<-"),
+    ('\u{2191}', "^"),
+    ('\u{2192}', "->"),
+    ('\u{2193}', "v"),
+    ('\u{2194}', "<->"),
+    ('\u{21D0}', "<="),
+    ('\u{21D2}', "=>"),
+    ('\u{21D4}', "<=>"),
+    ('\u{2212}', "-"),
+    ('\u{2215}', "/"),
+    ('\u{2217}', "*"),
+    ('\u{2219}', "."),
+    ('\u{221A}', "sqrt"),
+    ('\u{221E}', "inf"),
+    ('\u{223C}', "~"),
+    ('\u{2248}', "~"),
+    ('\u{2260}', "!="),
+    ('\u{2264}', "<="),
+    ('\u{2265}', ">="),
+    ('\u{226A}', "<<"),
+    ('\u{226B}', ">>"),
+    ('\u{2605}', "*"),
+    ('\u{2606}', "*"),
+    ('\u{2713}', "v"),
+    ('\u{2714}', "v"),
+    ('\u{2717}', "x"),
+    ('\u{2718}', "x"),
+    ('\u{3000}', " "),
+    ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"),
+    ('\u{FB02}', "fl"),
+    ('\u{FB03}', "ffi"),
+    ('\u{FB04}', "ffl"),
+    ('\u{FB05}', "st"),
+    ('\u{FB06}', "st"),
+    ('\u{FEFF}', ""),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_table_sorted() {
+        assert!(TRANSLITERATIONS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(TRANSLITERATIONS
+            .iter()
+            .all(|(c, s)| !c.is_ascii() && s.is_ascii()));
+    }
+
+    #[test]
+    fn test_transliterate() {
+        let ascii7 = Transliteration::default();
+        let text = "‘a’ “b” ‚c„ — – ‐ … • € £ ¥ ™ © ß æ Œ ĳ ﬀ Ａｚ０ č ř ő ų ẞ ©";
+        assert_eq!(
+            AsciiString::from_str_transliterated(text, ascii7).to_string(),
+            "'a' \"b\" 'c\" -- - - ... * EUR GBP JPY (TM) (C) ss ae OE ij ff Az0 c r o u SS (C)"
+        );
+        let latin1 = Transliteration::new(TransliterateTarget::Latin1);
+        assert_eq!(
+            AsciiString::from_str_transliterated("£ ß € ſ", latin1).to_string(),
+            "£ ß EUR s"
+        );
+        // decomposed (NFD) accents are dropped, for both targets.
+        let nfd = "cafe\u{301} Zu\u{308}rich n\u{303}\u{36F}";
+        assert_eq!(
+            AsciiString::from_str_transliterated(nfd, ascii7).to_string(),
+            "cafe Zurich n"
+        );
+        assert_eq!(
+            AsciiString::from_str_transliterated(nfd, latin1).to_string(),
+            "cafe Zurich n"
+        );
+        // 7-bit output never contains Extended ASCII, even for C1 controls.
+        let all: String = (0..=0xFFu8).map(char::from).collect();
+        let astring = AsciiString::from_str_transliterated(&all, ascii7);
+        assert!(astring.as_bytes().iter().all(u8::is_ascii));
+        let astring = AsciiString::from_str_transliterated(&all, latin1);
+        assert_eq!(astring.as_bytes(), (0..=0xFFu8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_unmappable() {
+        let text = "Ωx😀";
+        let ascii7 = Transliteration::default();
+        assert_eq!(ascii7.replacement(), Some(b'?'));
+        assert_eq!(
+            AsciiString::from_str_transliterated(text, ascii7).to_string(),
+            "?x?"
+        );
+        let sub = ascii7.with_replacement(0x1A);
+        assert_eq!(
+            AsciiString::from_str_transliterated(text, sub).as_bytes(),
+            [0x1A, b'x', 0x1A]
+        );
+        let skip = ascii7.skip_unmappable();
+        assert_eq!(skip.replacement(), None);
+        assert_eq!(
+            AsciiString::from_str_transliterated(text, skip).to_string(),
+            "x"
+        );
+        let latin1 = Transliteration::new(TransliterateTarget::Latin1).with_replacement(0xBF);
+        assert_eq!(
+            AsciiString::from_str_transliterated(text, latin1).as_bytes(),
+            [0xBF, b'x', 0xBF]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_extended_replacement_ascii7() {
+        let _ = Transliteration::default().with_replacement(0xFF);
+    }
+}
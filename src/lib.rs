@@ -13,6 +13,27 @@
 //! * u8 (ascii ordinals) can be accessed by index and updated directly.
 //! * Concatenation of char, u8, &str and AsciiString is supported (will panic if char/string is not valid ASCII).
 //! * Iterators for both u8 and char.
+//! * AsciiStr borrowed slices (AsciiString derefs to AsciiStr, like String to str).
+//! * AsciiDeque, a VecDeque backed string with O(1) push/pop at both ends.
+//! * AsciiChar enum for strongly typed single characters (one variant per u8 value).
+//! * Substring search (find, rfind, find_all, match_indices, starts_with, ends_with) for chars, strings and closures.
+//! * Lazy splitting iterators (split, splitn, rsplit, split_terminator, split_whitespace, split_ascii_whitespace, lines) yielding AsciiStr slices.
+//! * Editing without round-tripping through String (insert, insert_str, remove, retain, drain, replace_range, replace, replacen), accepting the same char, u8, &str and AsciiString inputs as concatenation.
+//! * Trimming (trim, trim_start, trim_end, trim_matches) and padding (pad_left, pad_right, center, truncate_or_pad) for fixed-width fields.
+//! * Case conversion (to_uppercase, to_lowercase, make_uppercase, make_lowercase) and case-insensitive comparison, 7-bit only or Latin-1 aware (CaseMode).
+//! * Range slicing (&astring[4..14]) into AsciiStr, plus get(range) and substring(range) for field extraction.
+//! * Code pages (CodePage) for decoding/encoding the Extended ASCII range as something other than Latin-1, such as CP437 (DOS box drawing), CP1252 (Windows) and ISO-8859-2/5/7/9/15, or your own SingleByteCodec, via to_string_with, display_with and try_from_str_with.
+//! * EBCDIC code pages (CP037, CP500, CP1047) with in-place from_ebcdic/into_ebcdic conversions and EbcdicStreamReader, which transcodes mainframe files into AsciiString lines (NL, LF and CR LF terminated).
+//! * Transliteration to ASCII (push_str_transliterate, from_str_transliterated).
+//! * Configurable lossy conversion (LossyPolicy, push_str_with_policy, from_str_with_policy).
+//! * Ascii7String, a validated 7-bit only string.
+//! * PrintableAsciiString and PrintableCtrlAsciiString, validated printable only strings.
+//! * Zero-copy as_str() for 7-bit contents, and faster String conversion.
+//! * Block-based fast paths for TryFrom<&str>, String::from and Display.
+//! * Line iterators for the stream readers (lines, lines_buffered).
+//! * Configurable line endings (LineEnding) for the stream readers and writers.
+//! * max_line_len guard on the stream readers.
+//! * std::io and futures io traits (Read, BufRead, Write and their async versions) on the stream types, AsciiString and &AsciiStr.
 //! * raw bytes via as_bytes() and as_bytes_mut(), where the bytes are guaranteed to be valid ASCII (one byte = one char).
 //! * stream support via AsciiStreamReader and AsciiStreamWriter (included in this crate).
 //! * async stream support via AsciiStreamReaderAsync and AsciiStreamWriterAsync (included in this crate).
@@ -197,12 +218,15 @@
 //!     assert_eq!(astring.to_string(), "ABCD ");
 //! ```
 //! Invalid Ascii
-//! * try_from will return an error if the string contains non-ascii characters.
+//! * try_from will return an AsciiError if the string contains non-ascii characters.
 //! ```rust
 //!     use cj_ascii::prelude::*;
 //!     let string = "ABC€";
 //!     let result = AsciiString::try_from(string);
 //!     assert!(result.is_err());
+//!     let error = result.unwrap_err();
+//!     assert_eq!(error.character(), '€');
+//!     assert_eq!(error.char_index(), 3);
 //! ```
 //! Streams
 //! * reader
@@ -335,26 +359,52 @@
 //! }
 //! ```
 
+pub mod ascii_7bit;
+pub mod ascii_case;
+pub mod ascii_char;
+pub mod ascii_codepage;
 pub mod ascii_common;
 pub mod ascii_consts;
+pub mod ascii_deque;
+pub mod ascii_ebcdic;
+pub mod ascii_error;
 pub mod ascii_group;
+pub mod ascii_lossy;
+pub mod ascii_pattern;
+pub mod ascii_printable;
+pub mod ascii_split;
+pub mod ascii_str;
 pub mod ascii_stream;
 #[cfg(feature = "async")]
 pub mod ascii_stream_async;
 pub mod ascii_string;
 pub mod ascii_traits;
 pub mod ascii_translators;
+pub mod ascii_transliterate;
 
 pub mod prelude {
+    pub use crate::ascii_7bit::*;
+    pub use crate::ascii_case::*;
+    pub use crate::ascii_char::*;
+    pub use crate::ascii_codepage::*;
     pub use crate::ascii_common::*;
     pub use crate::ascii_consts::*;
+    pub use crate::ascii_deque::*;
+    pub use crate::ascii_ebcdic::*;
+    pub use crate::ascii_error::*;
     pub use crate::ascii_group::*;
+    pub use crate::ascii_lossy::*;
+    pub use crate::ascii_pattern::*;
+    pub use crate::ascii_printable::*;
+    pub use crate::ascii_split::*;
+    pub use crate::ascii_str::*;
     pub use crate::ascii_stream::*;
     #[cfg(feature = "async")]
     pub use crate::ascii_stream_async::*;
     pub use crate::ascii_string::*;
     pub use crate::ascii_traits::*;
     pub use crate::ascii_translators::*;
+    pub use crate::ascii_transliterate::*;
 }
 
 #[doc = include_str!("../README.md")]